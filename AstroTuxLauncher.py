@@ -5,12 +5,14 @@ from os import path
 import argparse
 import json
 import tomli, tomli_w
+import yaml
 import dataclasses
 from dataclasses import dataclass, field
 from dataclasses_json import dataclass_json, config
-from typing import Optional, List
-from utils.misc import ExcludeIfNone, read_build_version, LAUNCHER_VERSION, CONTROL_CODES_SUPPORTED
-from utils.termutils import set_window_title
+from typing import Optional, List, Dict
+import typing
+from utils.misc import ExcludeIfNone, read_build_version, redact_secrets, LAUNCHER_VERSION, CONTROL_CODES_SUPPORTED, resolve_references
+from utils.termutils import set_window_title, ANSI
 from enum import Enum
 from pansi import ansi
 import utils.interface as interface
@@ -22,12 +24,25 @@ from utils import steam
 from utils.net import get_request
 from packaging import version
 import astro.playfab as playfab
-from astro.dedicatedserver import AstroDedicatedServer, ServerStatus
+from astro.dedicatedserver import AstroDedicatedServer, ServerStatus, DedicatedServerConfig, EngineConfig, PlayerList, ASTRO_DS_CONFIG_PATH, SERVER_ERROR_IDS, SERVER_LOG_BASE_FILENAME, get_clock_skew
+from astro.rcon import AstroRCON, ConsoleError, get_command_names, CONSOLE_COMMANDS
+from astro.remoteserver import RemoteDedicatedServer
+import astro.backup as backup
 import utils.net as net
 import signal
 import subprocess
+import shlex
+import gzip
+import collections
 import time
 import traceback
+import atexit
+import psutil
+import re
+import platform
+import zipfile
+import threading
+from urllib.parse import urlparse
 
 
 """
@@ -57,21 +72,79 @@ NAME = "AstroTuxLauncher"
 HELP_COMMAND = f"""What {NAME} should do
 
     - install: Installs the Astroneer Dedicated Server using steamcmd
-    - start: Starts the installed dedicated server
-    - update: Updates the Astroneer Dedicated Server using steamcmd
+    - start: Starts the installed dedicated server. Use --update_first to always install/update it before and not start it, if that fails
+    - update: Updates the Astroneer Dedicated Server using steamcmd. Use --dry_run to only check for an update, which exits with code 3 if one is available
+    - connect: Connects to the console of an already running Astroneer Dedicated Server to administer it remotely
+    - check-config: Checks the configuration and installation without starting the Astroneer Dedicated Server
+    - migrate: Imports the settings from another launcher config file (given using --from_path) into the config file
+    - status: Shows information about the Astroneer Dedicated Server installation and the running server, if reachable
+    - health: Checks, wether the Astroneer Dedicated Server is running, responsive and registered. Exits with code 0 if healthy and 1 otherwise
+    - logs: Shows the last lines of the newest (or given using --log_file) launcher log file
+    - watch: Shows the online players of a running Astroneer Dedicated Server (given using --host, --port and --console_password) and updates the list live until Ctrl+C is pressed
+    - version: Shows the version of the launcher. Use --full to also show the versions of the Astroneer Dedicated Server, WINE and Python, e.g. for bug reports
+    - config: Shows the effective launcher configuration resolved from the config file, the profile and the command line arguments with secrets redacted. Use --json to output it as JSON
+    - backup: Lists the backups of the savegames. Use --create to create one right away and --restore to restore one after stopping the running server, optionally starting it again afterwards using --start
+    - report: Bundles the effective configuration with secrets redacted, the log files of the last day and the versions of the components into a zip file in the current directory, which can be attached to a bug report
 """
 
-DEPOTDL_PATH = "libs/depotdownloader"
 DS_EXECUTABLE = "AstroServer.exe"
+DS_NATIVE_EXECUTABLE = "AstroServer"
+
+# Files, that have to exist and not be empty in a complete installation of the Astroneer DS (besides the executable)
+DS_REQUIRED_FILES = ["build.version", path.join("Astro", "Binaries", "Win64", "AstroServer-Win64-Shipping.exe"), path.join("Astro", "Content", "Paks", "Astro-WindowsServer.pak")]
+
+# File in the installation directory, that exists while an installation/update is in progress, such that interrupted ones are detected
+DS_INSTALL_MARKER = ".astrotux_install_incomplete"
 
 ASTRO_SERVER_STATS_URL = "https://servercheck.spycibot.com/stats"
 
+# Exit code of 'update --dry_run', if an update is available
+UPDATE_AVAILABLE_EXIT_CODE = 3
+
+# Config options, that can't be changed while the launcher is running
+RESTART_REQUIRED_OPTIONS = ["WorkingDirectory", "AstroServerPath", "UseWine", "OverrideWinePath", "DepotDownloaderPath", "WinePrefixPath", "LogPath", "DisableFileLog", "ServerOutputLog", "PIDFile", "DisableEncryption", "OverwritePublicIP", "PublicIP", "OverrideServerPort", "OverrideConsolePort", "status", "heartbeat"]
+
+CONFIG_FILE_NAME = "launcher.toml"
+
+# Locations, where the config file is searched in order, if no path is given explicitly
+CONFIG_SEARCH_PATHS = [
+    path.join(os.environ.get("XDG_CONFIG_HOME") or path.join(path.expanduser("~"), ".config"), "astrotux", CONFIG_FILE_NAME),
+    path.join("/etc", "astrotux", CONFIG_FILE_NAME),
+    path.join(path.dirname(path.abspath(__file__)), CONFIG_FILE_NAME),
+    path.abspath(CONFIG_FILE_NAME)
+]
+
+# Time in seconds, for which players, that joined or left, are highlighted by the 'watch' command
+WATCH_HIGHLIGHT_TIME = 30
+
+# Time in seconds to wait between repeated reachability checks of the server port
+NETWORK_CHECK_RETRY_DELAY = 10
+
+# Time in seconds to wait between checks, wether the Dedicated Server has shut down before restoring a backup
+RESTORE_SHUTDOWN_POLL_INTERVAL = 2
+
+# Maximum age in seconds of the log files included by the 'report' command
+REPORT_LOG_MAX_AGE = 24 * 3600
+
+DISCORD_WEBHOOK_REGEX = re.compile(r"^https://(?:(?:ptb|canary)\.)?discord(?:app)?\.com/api/webhooks/\d+/[\w-]+/?$")
+
 class LauncherCommand(Enum):
     """ Represents the command passed to the launcher """
     
     START = "start"
     INSTALL = "install"
     UPDATE = "update"
+    CONNECT = "connect"
+    CHECK_CONFIG = "check-config"
+    MIGRATE = "migrate"
+    STATUS = "status"
+    LOGS = "logs"
+    HEALTH = "health"
+    VERSION = "version"
+    WATCH = "watch"
+    CONFIG = "config"
+    REPORT = "report"
+    BACKUP = "backup"
 
 
 
@@ -84,25 +157,84 @@ class NotificationMethod(Enum):
     
     NONE = ""
     NTFY = "ntfy"
+    GOTIFY = "gotify"
+    MATRIX = "matrix"
+    APPRISE = "apprise"
+    EMAIL = "email"
     DISCORD = "discord"
 
+class NetworkCheckTiming(Enum):
+    """ Represents, when the reachability of the server port should be checked """
+    
+    BEFORE_START = "before_start"
+    AFTER_REGISTRATION = "after_registration"
+
 @dataclass
 class DiscordConfig:
     webhookURL: str = None
+    colors: Dict[str, int] = field(default_factory=dict)    # Embed colors overriding the default color of an event, by event type
+    thumbnailURL: Optional[str] = field(metadata=config(exclude=ExcludeIfNone), default=None)      # URL of an image to show as thumbnail of embeds, only used, if set
+    authorIconURL: Optional[str] = field(metadata=config(exclude=ExcludeIfNone), default=None)     # URL of an image to show next to the author of embeds, only used, if set
+    level: Optional[str] = field(metadata=config(exclude=ExcludeIfNone), default=None)     # Minimum level of events to send with this method, all whitelisted events are sent, if not set
+    silentBelowLevel: str = "warning"   # Events below this level are posted without notifying the channel members
 
 @dataclass
 class NTFYConfig:
     topic: str = None
     serverURL: str = "https://ntfy.sh"
+    click: Dict[str, str] = field(default_factory=dict)                     # URLs to open when clicking the notification of an event, by event type
+    actions: Dict[str, List[Dict[str, str]]] = field(default_factory=dict)  # Action buttons to add to the notification of an event, by event type
+    priorities: Dict[str, int] = field(default_factory=dict)                # Priorities overriding the default priority of an event, by event type
+    topics: Dict[str, str] = field(default_factory=dict)                    # Topics overriding {topic} by event type or by the minimum level of events
+    level: Optional[str] = field(metadata=config(exclude=ExcludeIfNone), default=None)     # Minimum level of events to send with this method, all whitelisted events are sent, if not set
+
+@dataclass
+class GotifyConfig:
+    token: str = None
+    serverURL: str = None
+    level: Optional[str] = field(metadata=config(exclude=ExcludeIfNone), default=None)     # Minimum level of events to send with this method, all whitelisted events are sent, if not set
+
+@dataclass
+class AppriseConfig:
+    urls: List[str] = field(default_factory=list)
+    executable: str = "apprise"
+    level: Optional[str] = field(metadata=config(exclude=ExcludeIfNone), default=None)     # Minimum level of events to send with this method, all whitelisted events are sent, if not set
+
+@dataclass
+class EmailConfig:
+    smtpHost: str = None
+    smtpPort: int = 587
+    security: str = "starttls"
+    username: Optional[str] = field(metadata=config(exclude=ExcludeIfNone), default=None)
+    password: Optional[str] = field(metadata=config(exclude=ExcludeIfNone), default=None)
+    sender: str = None
+    recipients: List[str] = field(default_factory=list)
+    level: Optional[str] = field(metadata=config(exclude=ExcludeIfNone), default=None)     # Minimum level of events to send with this method, all whitelisted events are sent, if not set
+
+@dataclass
+class MatrixConfig:
+    homeserverURL: str = None
+    accessToken: str = None
+    roomID: str = None
+    level: Optional[str] = field(metadata=config(exclude=ExcludeIfNone), default=None)     # Minimum level of events to send with this method, all whitelisted events are sent, if not set
 
 @dataclass
 class NotificationConfig:
     method: NotificationMethod = NotificationMethod.NONE
     name: str = "Astroneer Dedicated Server"
-    EventWhitelist: List[interface.EventType] = field(default_factory=lambda: [e for e in interface.EventType])
+    EventWhitelist: List[interface.EventType] = field(default_factory=lambda: [e for e in interface.EventType if e not in [interface.EventType.AUTOSAVE, interface.EventType.HEARTBEAT]])
+    Timeout: float = 10     # Time in seconds to wait for the notification service to answer, before giving up on a notification
+    Retries: int = 3        # How often to try sending a notification again, if it failed
+    EventLoggerName: str = interface.EVENT_LOGGER_NAME  # Name of the logger, that events are logged to the console and log file with
+    SpoolNotifications: bool = False    # Wether to store queued notifications in a file in the log directory, such that they are sent after a crash of the launcher
+    templates: Dict[str, str] = field(default_factory=dict)    # Message formats overriding the default format of an event, by event type
     
     discord: Optional[DiscordConfig] = field(metadata=config(exclude=ExcludeIfNone), default=None)
     ntfy: Optional[NTFYConfig] = field(metadata=config(exclude=ExcludeIfNone), default=None)
+    gotify: Optional[GotifyConfig] = field(metadata=config(exclude=ExcludeIfNone), default=None)
+    matrix: Optional[MatrixConfig] = field(metadata=config(exclude=ExcludeIfNone), default=None)
+    apprise: Optional[AppriseConfig] = field(metadata=config(exclude=ExcludeIfNone), default=None)
+    email: Optional[EmailConfig] = field(metadata=config(exclude=ExcludeIfNone), default=None)
 
 @dataclass
 class StatusConfig:
@@ -110,13 +242,38 @@ class StatusConfig:
     Interval: int = 120         # Interval in which to send status updates
     EndpointURL: str = ""       # URL to send status updates as GET requests to
 
+@dataclass
+class HeartbeatConfig:
+    Interval: float = 0     # Time in minutes between heartbeats while the server is running and registered. 0 disables heartbeats
+    PingURL: Optional[str] = field(metadata=config(exclude=ExcludeIfNone), default=None)    # URL to send a GET request to with every heartbeat, only used, if set
+
+@dataclass
+class BackupConfig:
+    BackupBeforeUpdate: bool = False    # Wether to back up the savegames and configuration before updating
+    IncludeConfig: bool = True          # Wether to include the Dedicated Server configuration files in backups
+    BackupPath: str = "backups"         # The path where backups should be saved
+    MaxBackups: int = 10                # Maximum number of backups of each kind to keep. 0 keeps all backups
+    BackupInterval: float = 0           # Time in hours between scheduled backups of the savegames while the server is running. 0 disables scheduled backups
+
 @dataclass_json
 @dataclass
 class LauncherConfig:
     AutoUpdateServer: bool = True   # Wether to automatically install/update the Astroneer DS at start if update is available
     
     CheckNetwork: bool = True       # Wether to perform a network check before starting the Astroneer DS
-    OverwritePublicIP: bool = False # Wether to overwrite the PublicIP DS config option with the fetched public IP
+    NetworkCheckTime: NetworkCheckTiming = NetworkCheckTiming.BEFORE_START  # Wether to check the reachability of the server port before starting the Astroneer DS or after it has registered
+    NetworkCheckRetryTime: float = 0    # Time in seconds for which a failed reachability check is repeated, before the server port is reported as unreachable
+    
+    ShutdownGraceTimeout: float = 60    # Time in seconds to wait for the Astroneer DS to exit after asking it to shut down, before stopping it forcefully. 0 waits forever
+    AutoRestartOnCrash: bool = False    # Wether to automatically restart the Astroneer DS after it crashed
+    CrashLoopMaxRestarts: int = 3       # Maximum number of automatic restarts within {CrashLoopWindow} before giving up
+    CrashLoopWindow: float = 600        # Time window in seconds, in which automatic restarts are counted
+    RestartOnEvents: List[str] = field(default_factory=list)    # Event types and server error IDs, that cause the Astroneer DS to be restarted
+    RestartOnEventCooldown: float = 1800    # Minimum time in seconds between restarts caused by {RestartOnEvents}
+    OverwritePublicIP: bool = False # Wether to overwrite the PublicIP DS config option with {PublicIP} or the fetched public IP
+    PublicIP: Optional[str] = field(metadata=config(exclude=ExcludeIfNone), default=None)   # Public IP to force into the DS config, only used, if set and {OverwritePublicIP} is enabled
+    OverrideServerPort: Optional[int] = field(metadata=config(exclude=ExcludeIfNone), default=None)     # Port of the Astroneer DS (UDP), only used, if set
+    OverrideConsolePort: Optional[int] = field(metadata=config(exclude=ExcludeIfNone), default=None)    # Console port of the Astroneer DS (TCP), only used, if set
     
     # Settings related to notifications
     notifications: NotificationConfig = field(default_factory=NotificationConfig)    # Configuration for notifications
@@ -124,39 +281,390 @@ class LauncherConfig:
     # Settings related to sending status updates
     status: StatusConfig = field(default_factory=StatusConfig)
     
+    # Settings related to backups
+    backup: BackupConfig = field(default_factory=BackupConfig)
+    
+    # Settings related to heartbeats for dead man's switch monitoring
+    heartbeat: HeartbeatConfig = field(default_factory=HeartbeatConfig)
+    
     LogDebugMessages: bool = False  # Wether the the console and log file should include log messages with level logging.DEBUG
+    ConsoleLogFormat: interface.LogFormat = interface.LogFormat.COMPACT    # How log messages are formatted on the console. The log file always uses the compact format
     
+    WorkingDirectory: Optional[str] = field(metadata=config(exclude=ExcludeIfNone), default=None)   # Directory to change to at start, which relative paths are resolved against. Only used, if set
     AstroServerPath: str = "AstroneerServer"    # The path, where the Astroneer DS installation should reside
+    UseWine: bool = True                        # Wether to run the Astroneer DS using WINE. If disabled, a native Linux executable is run directly
     OverrideWinePath: Optional[str] = field(metadata=config(exclude=ExcludeIfNone), default=None)   # Path to wine executable, only used, if set
+    DepotDownloaderPath: str = "libs/depotdownloader"   # The path of the DepotDownloader executable, which is downloaded automatically, if missing
     WinePrefixPath: str = "winepfx"             # The path, where the Wine prefix should be stored
     LogPath: str = "logs"                       # The path where logs should be saved
+    DisableFileLog: bool = False                # Wether to only log to the console and not write log files
+    ServerOutputLog: bool = False               # Wether to write the raw output of the Dedicated Server to a separate log file
+    KeepUncompressedLogs: Optional[int] = field(metadata=config(exclude=ExcludeIfNone), default=None)  # Number of old log files to keep uncompressed, older ones are compressed. Only used, if set
+    PIDFile: Optional[str] = field(metadata=config(exclude=ExcludeIfNone), default=None)            # Path to write the PID of the launcher to, only used, if set
     
     PlayfabAPIInterval: int = 2                 # Time to wait between Playfab API requests
     ServerStatusInterval: float = 3             # Time to wait between Server Status checks
+    EventHistorySize: int = 100                 # Number of recent server events to keep in memory for the 'events' command
+    AutoSaveInterval: float = 0                 # Time in minutes between automatic saves of the game. 0 disables automatic saving
+    ConsoleCommandTimeout: float = 5            # Time to wait for the Dedicated Server to answer a console command
+    ConsoleKeepAlive: float = 0                 # Idle time in seconds after which keep-alive packets are sent on console connections. 0 disables keep-alive
+    ConsoleCommandNames: Dict[str, str] = field(default_factory=dict)  # Names to send for console commands overruling the default ones, by command
+    ResourceMonitorInterval: float = 60         # Time to wait between resource usage checks of the Dedicated Server process. 0 disables the checks
+    MemoryWarningThreshold: Optional[int] = field(metadata=config(exclude=ExcludeIfNone), default=None)  # Memory usage in MiB above which a warning is logged, only used, if set
+    
+    RequiredDiskSpace: float = 5    # Free disk space in GiB required for installing/updating the Astroneer DS. 0 disables the check
+    
+    AllowRoot: bool = False         # Wether to hide the warning shown when the launcher is running as root
     
     DisableEncryption: bool = True  # Wether to disable encryption for the Astroneer DS. CURRENTLY REQUIRED TO BE "True" FOR HOSTING ON LINUX
         
+    def validate(self):
+        """
+            Checks the configuration for invalid values
+            
+            Returns: A list of messages describing the problems found, which is empty if the configuration is valid
+        """
+        
+        def is_http_url(url):
+            parsed = urlparse(url)
+            return (parsed.scheme in ["http", "https"]) and (parsed.netloc != "")
+        
+        problems = []
+        
+        event_types = [e.value for e in interface.EventType]
+        
+        for method in NotificationMethod:
+            if method == NotificationMethod.NONE:
+                continue
+            
+            method_config = getattr(self.notifications, method.value)
+            
+            if method_config and (method_config.level is not None) and (method_config.level not in interface.NOTIFICATION_LEVELS):
+                problems.append(f"notifications.{method.value}.level: Has to be one of {', '.join(repr(l) for l in interface.NOTIFICATION_LEVELS)}")
+        
+        if self.notifications.discord and (self.notifications.discord.silentBelowLevel not in interface.NOTIFICATION_LEVELS):
+            problems.append(f"notifications.discord.silentBelowLevel: Has to be one of {', '.join(repr(l) for l in interface.NOTIFICATION_LEVELS)}")
+        
+        if (self.notifications.method == NotificationMethod.DISCORD) and self.notifications.discord:
+            if self.notifications.discord.webhookURL and not DISCORD_WEBHOOK_REGEX.match(self.notifications.discord.webhookURL):
+                problems.append("notifications.discord.webhookURL: Has to be a Discord webhook URL (https://discord.com/api/webhooks/...)")
+            
+            for option in ["thumbnailURL", "authorIconURL"]:
+                url = getattr(self.notifications.discord, option)
+                
+                if (url is not None) and not is_http_url(url):
+                    problems.append(f"notifications.discord.{option}: Has to be a http(s) URL")
+        
+        if (self.notifications.method == NotificationMethod.NTFY) and self.notifications.ntfy:
+            if not is_http_url(self.notifications.ntfy.serverURL):
+                problems.append("notifications.ntfy.serverURL: Has to be a http(s) URL")
+            
+            for event_type, priority in self.notifications.ntfy.priorities.items():
+                if (event_type in event_types) and (not isinstance(priority, int) or not (1 <= priority <= 5)):
+                    problems.append(f"notifications.ntfy.priorities: Priority of '{event_type}' has to be between 1 and 5")
+        
+        if (self.notifications.method == NotificationMethod.GOTIFY) and self.notifications.gotify and self.notifications.gotify.serverURL:
+            if not is_http_url(self.notifications.gotify.serverURL):
+                problems.append("notifications.gotify.serverURL: Has to be a http(s) URL")
+        
+        if (self.notifications.method == NotificationMethod.MATRIX) and self.notifications.matrix and self.notifications.matrix.homeserverURL:
+            if not is_http_url(self.notifications.matrix.homeserverURL):
+                problems.append("notifications.matrix.homeserverURL: Has to be a http(s) URL")
+        
+        if (self.notifications.method == NotificationMethod.EMAIL) and self.notifications.email:
+            if self.notifications.email.security not in ["starttls", "tls", "none"]:
+                problems.append("notifications.email.security: Has to be one of 'starttls', 'tls' or 'none'")
+            
+            if not (1 <= self.notifications.email.smtpPort <= 65535):
+                problems.append("notifications.email.smtpPort: Has to be a valid port number")
+        
+        if self.status.SendStatus and not is_http_url(self.status.EndpointURL):
+            problems.append("status.EndpointURL: Has to be a http(s) URL, if SendStatus is enabled")
+        
+        if self.status.Interval <= 0:
+            problems.append("status.Interval: Has to be greater than 0")
+        
+        if self.heartbeat.Interval < 0:
+            problems.append("heartbeat.Interval: Has to be 0 or greater")
+        
+        if (self.heartbeat.PingURL is not None) and not is_http_url(self.heartbeat.PingURL):
+            problems.append("heartbeat.PingURL: Has to be a http(s) URL")
+        
+        if self.PlayfabAPIInterval <= 0:
+            problems.append("PlayfabAPIInterval: Has to be greater than 0")
+        
+        if self.ServerStatusInterval <= 0:
+            problems.append("ServerStatusInterval: Has to be greater than 0")
+        
+        if self.ShutdownGraceTimeout < 0:
+            problems.append("ShutdownGraceTimeout: Has to be 0 or greater")
+        
+        if self.NetworkCheckRetryTime < 0:
+            problems.append("NetworkCheckRetryTime: Has to be 0 or greater")
+        
+        if self.AutoSaveInterval < 0:
+            problems.append("AutoSaveInterval: Has to be 0 or greater")
+        
+        if self.ConsoleCommandTimeout <= 0:
+            problems.append("ConsoleCommandTimeout: Has to be greater than 0")
+        
+        if self.EventHistorySize < 0:
+            problems.append("EventHistorySize: Has to be 0 or greater")
+        
+        if self.ConsoleKeepAlive < 0:
+            problems.append("ConsoleKeepAlive: Has to be 0 or greater")
+        
+        for command, name in self.ConsoleCommandNames.items():
+            if not (command in CONSOLE_COMMANDS):
+                problems.append(f"ConsoleCommandNames: '{command}' is not a known console command")
+            elif (name.strip() == "") or (len(name.split()) > 1):
+                problems.append(f"ConsoleCommandNames: Name of '{command}' has to be a single word")
+        
+        if self.RequiredDiskSpace < 0:
+            problems.append("RequiredDiskSpace: Has to be 0 or greater")
+        
+        if self.notifications.EventLoggerName.strip() == "":
+            problems.append("notifications.EventLoggerName: Must not be empty")
+        
+        if self.notifications.Timeout <= 0:
+            problems.append("notifications.Timeout: Has to be greater than 0")
+        
+        if self.notifications.Retries < 0:
+            problems.append("notifications.Retries: Has to be 0 or greater")
+        
+        if self.backup.BackupInterval < 0:
+            problems.append("backup.BackupInterval: Has to be 0 or greater")
+        
+        if self.backup.MaxBackups < 0:
+            problems.append("backup.MaxBackups: Has to be 0 or greater")
+        
+        if self.CrashLoopMaxRestarts < 1:
+            problems.append("CrashLoopMaxRestarts: Has to be at least 1")
+        
+        if self.RestartOnEventCooldown < 0:
+            problems.append("RestartOnEventCooldown: Has to be 0 or greater")
+        
+        for option in ["OverrideServerPort", "OverrideConsolePort"]:
+            port = getattr(self, option)
+            
+            if (port is not None) and not (1 <= port <= 65535):
+                problems.append(f"{option}: Has to be a port number between 1 and 65535")
+        
+        if (self.KeepUncompressedLogs is not None) and (self.KeepUncompressedLogs < 0):
+            problems.append("KeepUncompressedLogs: Has to be 0 or greater")
+        
+        if (self.PublicIP is not None) and not net.valid_ip(self.PublicIP):
+            problems.append("PublicIP: Has to be a valid IPv4 address")
+        
+        if (self.OverrideServerPort is not None) and (self.OverrideServerPort == self.OverrideConsolePort):
+            problems.append("OverrideConsolePort: Has to be different from OverrideServerPort")
+        
+        return problems
+    
     @staticmethod
-    def ensure_toml_config(config_path):
+    def load_file(config_path):
         """
-            Reads the launcher configuration and fist creates the config file if not present, populated with the default values
+            Reads all sections of the config file at {config_path}.
+            Files ending in '.yaml' or '.yml' are read as YAML, all others as TOML
+            
+            Returns: The dictionary containing the contents of the file
         """
         
-        config = None
+        if not path.isfile(config_path):
+            raise ValueError("Specified config path doesn't point to a file!")
         
-        if path.exists(config_path):
-            # If config file exists, read it into a config object
-            if not path.isfile(config_path):
-                raise ValueError("Specified config path doesn't point to a file!")
+        with open(config_path, "rb") as cf:
+            if path.splitext(config_path)[1].lower() in [".yaml", ".yml"]:
+                config_dict = yaml.safe_load(cf)
+            else:
+                config_dict = tomli.load(cf)
+        
+        return config_dict if isinstance(config_dict, dict) else {}
+    
+    @staticmethod
+    def read_config_file(config_path):
+        """
+            Reads the launcher section of the config file at {config_path}.
+            Files ending in '.yaml' or '.yml' are read as YAML, all others as TOML
+            
+            Returns: The dictionary containing the launcher configuration
+        """
+        
+        config_dict = LauncherConfig.load_file(config_path)
+        
+        # If no "launcher" section is present in the file, use an empty one
+        if not ("launcher" in config_dict.keys()):
+            return {}
+        
+        return config_dict["launcher"]
+    
+    @staticmethod
+    def read_profile(config_path, profile):
+        """
+            Reads the profile {profile} from the 'profiles' section of the config file at {config_path}
+            
+            Returns: The dictionary containing the options, that the profile overrides
+        """
+        
+        profiles = LauncherConfig.load_file(config_path).get("profiles", {})
+        
+        if not isinstance(profiles, dict) or not (profile in profiles):
+            raise ValueError(f"Profile '{profile}' doesn't exist in the config file")
+        
+        return profiles[profile]
+    
+    @staticmethod
+    def read_console_log_format(config_path, profile=None):
+        """
+            Reads only the ConsoleLogFormat option from the config file at {config_path}, with the profile {profile} applied, if given.
+            Used to format the console output correctly before the configuration is loaded
+            
+            Returns: The LogFormat set in the config file or None, if it isn't set or couldn't be read
+        """
+        
+        try:
+            config_dict = LauncherConfig.read_config_file(config_path)
+            
+            if profile is not None:
+                config_dict = LauncherConfig.merge_dicts(config_dict, LauncherConfig.read_profile(config_path, profile))
+            
+            return interface.LogFormat(config_dict["ConsoleLogFormat"])
+        except Exception:
+            return None
+    
+    @staticmethod
+    def merge_dicts(base, overrides):
+        """ Returns a copy of the (config) dictionary {base} with the values from {overrides} applied. Nested dictionaries are merged too """
+        
+        merged = dict(base)
+        
+        for key, value in overrides.items():
+            if isinstance(value, dict) and isinstance(merged.get(key), dict):
+                merged[key] = LauncherConfig.merge_dicts(merged[key], value)
+            else:
+                merged[key] = value
+        
+        return merged
+    
+    def write_config_file(self, config_path):
+        """
+            Writes the configuration to the config file at {config_path}, keeping other sections of the file like the profiles.
+            Files ending in '.yaml' or '.yml' are written as YAML, all others as TOML
+        """
+        
+        config_dict = LauncherConfig.load_file(config_path) if path.isfile(config_path) else {}
+        config_dict["launcher"] = self.to_dict(encode_json=True)
+        
+        if path.splitext(config_path)[1].lower() in [".yaml", ".yml"]:
+            with open(config_path, "w") as cf:
+                yaml.safe_dump(config_dict, cf, sort_keys=False)
+        else:
+            with open(config_path, "wb") as cf:
+                tomli_w.dump(config_dict, cf)
+    
+    def find_warnings(self):
+        """
+            Checks the configuration for values, that are most likely mistakes, but don't keep the launcher from working, like event types in the notification settings, that don't exist.
+            The affected entries are ignored when sending notifications
+            
+            Returns: A list of messages describing the suspicious values found
+        """
+        
+        warnings = []
+        
+        event_types = [e.value for e in interface.EventType]
+        
+        # Settings of notification methods, that are not used right now, are checked too, to catch mistakes before switching to them
+        if self.notifications.discord:
+            for event_type, color in self.notifications.discord.colors.items():
+                if event_type not in event_types:
+                    warnings.append(f"notifications.discord.colors: '{event_type}' is not an event type and is ignored")
+                elif not isinstance(color, int) or not (0 <= color <= 0xFFFFFF):
+                    warnings.append(f"notifications.discord.colors: Color of '{event_type}' is not an integer between 0 and 16777215 and is ignored")
+        
+        if self.notifications.ntfy:
+            for option in ["priorities", "click", "actions"]:
+                for event_type in getattr(self.notifications.ntfy, option).keys():
+                    if event_type not in event_types:
+                        warnings.append(f"notifications.ntfy.{option}: '{event_type}' is not an event type and is ignored")
             
-            with open(config_path, "rb") as tf:
-                toml_dict = tomli.load(tf)
+            for key in self.notifications.ntfy.topics.keys():
+                if (key not in event_types) and (key not in interface.NOTIFICATION_LEVELS):
+                    warnings.append(f"notifications.ntfy.topics: '{key}' is neither an event type nor a level and is ignored")
+        
+        for event_type, template in self.notifications.templates.items():
+            if event_type not in event_types:
+                warnings.append(f"notifications.templates: '{event_type}' is not an event type and is ignored")
+            elif not interface.valid_format(template):
+                warnings.append(f"notifications.templates: Template of '{event_type}' contains unmatched braces and is ignored")
+        
+        for trigger in self.RestartOnEvents:
+            if (trigger not in event_types) and (trigger not in SERVER_ERROR_IDS):
+                warnings.append(f"RestartOnEvents: '{trigger}' is neither an event type nor a server error ID and is ignored")
+        
+        return warnings
+    
+    @staticmethod
+    def find_unknown_keys(config_dict, config_class=None, prefix=""):
+        """
+            Finds keys in {config_dict}, that don't correspond to a config option
             
-            # If no "launcher" section is present in the file, create it as empty
-            if not ("launcher" in toml_dict.keys()):
-                toml_dict = {"launcher": {}}
+            Arguments:
+                - config_dict: The dictionary to check
+                - [config_class]: The dataclass that {config_dict} should correspond to (Default: LauncherConfig)
+                - [prefix]: Prefix to use for the returned keys
             
-            config = LauncherConfig.from_dict(toml_dict["launcher"])
+            Returns: A list of the unknown keys in dotted notation
+        """
+        
+        if config_class is None:
+            config_class = LauncherConfig
+        
+        type_hints = typing.get_type_hints(config_class)
+        unknown_keys = []
+        
+        for key, value in config_dict.items():
+            if not (key in type_hints):
+                unknown_keys.append(f"{prefix}{key}")
+                continue
+            
+            # Unwrap Optional types to check nested config sections
+            field_type = type_hints[key]
+            candidates = [t for t in typing.get_args(field_type) if t is not type(None)] if typing.get_origin(field_type) is typing.Union else [field_type]
+            
+            if isinstance(value, dict) and (len(candidates) == 1) and dataclasses.is_dataclass(candidates[0]):
+                unknown_keys += LauncherConfig.find_unknown_keys(value, candidates[0], prefix=f"{prefix}{key}.")
+        
+        return unknown_keys
+    
+    @staticmethod
+    def find_config_file():
+        """
+            Searches the standard locations for a launcher config file in the order of CONFIG_SEARCH_PATHS
+            
+            Returns: The path of the first config file found or the path in the working directory, if none exists
+        """
+        
+        for config_path in CONFIG_SEARCH_PATHS:
+            if path.isfile(config_path):
+                return config_path
+        
+        return path.abspath(CONFIG_FILE_NAME)
+    
+    @staticmethod
+    def ensure_config(config_path, profile=None):
+        """
+            Reads the launcher configuration and fist creates the config file if not present, populated with the default values.
+            Files ending in '.yaml' or '.yml' are read and written as YAML, all others as TOML.
+            If {profile} is given, the options of the profile are applied on top of the launcher section.
+            References to environment variables and files in the values are resolved after writing the file, such that they stay in it
+        """
+        
+        config = None
+        
+        if path.exists(config_path):
+            # If config file exists, read it into a config object
+            config = LauncherConfig.from_dict(LauncherConfig.read_config_file(config_path))
 
         else:
             # If config file is not present, create directories and default config
@@ -167,67 +675,137 @@ class LauncherConfig:
         
         # Write config back to file to add missing entried and remove superflous ones
         # In the case of the file not existing prior, it will be created
-        config_dict = {"launcher": config.to_dict(encode_json=True)}
+        config.write_config_file(config_path)
         
-        with open(config_path, "wb") as tf:
-            tomli_w.dump(config_dict, tf)
+        config_dict = config.to_dict(encode_json=True)
         
-        return config
+        if profile is not None:
+            config_dict = LauncherConfig.merge_dicts(config_dict, LauncherConfig.read_profile(config_path, profile))
+        
+        return LauncherConfig.from_dict(resolve_references(config_dict))
 
 class AstroTuxLauncher():
     
-    def __init__(self, config_path, astro_path, depotdl_exec, force_debug_log=False):
+    def __init__(self, config_path, astro_path, depotdl_exec, force_debug_log=False, remote=False, check_only=False, server_port=None, console_port=None, wine_prefix=None, wine_path=None, log_to_file=True, working_dir=None, no_file_log=False, profile=None, log_format=None):
         self.dedicatedserver = None
+        self.remoteserver = None
         self.status_thread = None
+        self.heartbeat_thread = None
+        
+        # Requests received using signals, which are handled by the server loop
+        self.save_requested = False
+        self.log_roll_requested = False
         
-        # Setup basic logging
+        # Setup basic logging. A format given on the command line is used right away, such that all messages use it
         interface.LauncherLogging.prepare()
+        
+        if log_format is not None:
+            interface.LauncherLogging.set_console_format(log_format)
+        
         interface.LauncherLogging.setup_console()
         
+        # Remember CLI overrides, such that they are also applied when reloading the config
+        self.astro_path_override = astro_path
+        self.force_debug_log = force_debug_log
+        self.no_file_log = no_file_log
+        self.profile = profile
+        self.server_port_override = server_port
+        self.console_port_override = console_port
+        self.wine_prefix_override = wine_prefix
+        self.wine_path_override = wine_path
+        self.log_format_override = log_format
+        
         try:
+            # If no config path is given explicitly, look for it in the standard locations
+            if config_path is None:
+                config_path = LauncherConfig.find_config_file()
+            
             self.config_path = path.abspath(config_path)
             
             LOGGER.info(f"Configuration file path: {self.config_path}")
             
-            self.config = LauncherConfig.ensure_toml_config(self.config_path)
+            if profile is not None:
+                LOGGER.info(f"Using profile: {profile}")
+            
+            config = LauncherConfig.ensure_config(self.config_path, profile)
         except Exception as e:
             LOGGER.error(f"Error while loading config file ({type(e).__name__}): {str(e)}")
             LOGGER.error(f"Please check the config path parameter and/or config file")
             self.exit()
         
-        # If cli parameter is specified, it overrides the config value
-        if not (astro_path is None):
-            self.config = dataclasses.replace(self.config, {"AstroServerPath": astro_path})
+        # Change the working directory before resolving relative paths. A relative path in the config is relative to the config file
+        if (working_dir is None) and (config.WorkingDirectory is not None):
+            working_dir = path.join(path.dirname(self.config_path), config.WorkingDirectory)
         
-        # If flag was passed, overrule config option
-        if force_debug_log:
-            self.config.LogDebugMessages = True
+        if working_dir is not None:
+            try:
+                os.chdir(working_dir)
+            except OSError as e:
+                LOGGER.error(f"Could not change to working directory '{working_dir}': {str(e)}")
+                self.exit(reason="Invalid working directory")
         
-        # Make sure we use absolute paths
-        self.config.AstroServerPath = path.abspath(self.config.AstroServerPath)
-        self.config.WinePrefixPath = path.abspath(self.config.WinePrefixPath)
-        self.config.LogPath = path.abspath(self.config.LogPath)
+        self.config = self.apply_config_overrides(config)
+        
+        # Report all problems with the configuration at once
+        self.config_problems = self.config.validate()
+        
+        if (len(self.config_problems) > 0) and not check_only:
+            LOGGER.error("The configuration file contains invalid values:")
+            
+            for problem in self.config_problems:
+                LOGGER.error(f"    - {problem}")
+            
+            self.exit(reason="Invalid configuration")
+        
+        self.config_warnings = self.config.find_warnings()
+        
+        for warning in self.config_warnings:
+            LOGGER.warning(f"Configuration: {warning}")
         
         # Apply wine path override if possible and check that is exists
         self.wineexec = shutil.which("wine")
         self.wineserverexec = shutil.which("wineserver")
         
-        if self.config.OverrideWinePath is not None and path.isfile(self.config.OverrideWinePath):
-            self.wineexec = path.abspath(self.config.OverrideWinePath)
-            self.wineserverexec = path.join(path.dirname(self.wineexec), "wineserver")
+        if self.config.OverrideWinePath is not None:
+            if path.isfile(self.config.OverrideWinePath):
+                self.wineexec = path.abspath(self.config.OverrideWinePath)
+                self.wineserverexec = path.join(path.dirname(self.wineexec), "wineserver")
+                
+                if not path.isfile(self.wineserverexec):
+                    LOGGER.warning(f"No wineserver executable found next to the overridden wine executable ({self.wineserverexec})")
+                    self.wineserverexec = None
+            else:
+                LOGGER.warning(f"The overridden wine path ({self.config.OverrideWinePath}) doesn't point to a file, using system binary")
         
-        if (self.wineexec is None) or (self.wineserverexec is None):
+        # Wine is not needed for administering a remote Dedicated Server or running a native one
+        if ((self.wineexec is None) or (self.wineserverexec is None)) and self.config.UseWine and not (remote or check_only):
             LOGGER.error("Wine (or Wineserver) executable not found!")
             LOGGER.error("Make sure that you have wine installed and accessible")
             LOGGER.error("or set 'OverrideWinePath' config option to the path of the wine executable")
             self.exit()
         
+        # Don't send requests to the Playfab API more often than configured
+        playfab.set_request_interval(self.config.PlayfabAPIInterval)
+        
         # Finish setting up logging
         interface.LauncherLogging.set_log_debug(self.config.LogDebugMessages)
-        interface.LauncherLogging.setup_logfile(self.config.LogPath)
+        interface.LauncherLogging.set_console_format(self.config.ConsoleLogFormat)
+        
+        if log_to_file and not self.config.DisableFileLog and interface.LauncherLogging.setup_logfile(self.config.LogPath):
+            if self.config.KeepUncompressedLogs is not None:
+                interface.LauncherLogging.compress_old_logs(self.config.LogPath, self.config.KeepUncompressedLogs)
+            
+            if not (remote or check_only):
+                self.warn_debug_file_log()
         
         self.launcherPath = os.getcwd()
         
+        # Make sure only one launcher manages the Dedicated Server at a time
+        if (self.config.PIDFile is not None) and not (remote or check_only):
+            if not self.acquire_pid_file():
+                LOGGER.error(f"Another instance of {NAME} is already running (PID file: {self.config.PIDFile})")
+                self.exit(reason="Another instance is already running")
+        
         self.depotdl_path = None
         
         # If argument is given, file has to exist
@@ -243,17 +821,17 @@ class AstroTuxLauncher():
             else:
                 LOGGER.warning("The given DepotDownloader path doesn't point to a file, using default path")
         
-        # If argument is not given, default path is used and may not exists yet, so create directories
+        # If argument is not given, configured path is used and may not exists yet, so create directories
         if self.depotdl_path is None:
-            self.depotdl_path = path.abspath(DEPOTDL_PATH)
+            self.depotdl_path = self.config.DepotDownloaderPath
             os.makedirs(path.dirname(self.depotdl_path), exist_ok=True)
         
         # Log some information about loaded paths, configs, etc.
         LOGGER.info(f"Working directory: {self.launcherPath}")
-        LOGGER.debug(f"Launcher configuration (including overrides):\n{json.dumps(self.config.to_dict(encode_json=True), indent=4)}")
+        LOGGER.debug(f"Launcher configuration (including overrides):\n{json.dumps(redact_secrets(self.config.to_dict(encode_json=True)), indent=4)}")
         
         # Initialize console command parser
-        self.console_parser = interface.ConsoleParser()
+        self.console_parser = interface.ConsoleParser(remote=remote)
         self.cmd_queue = Queue()
         
         # Initialize Input Thread to handle console input later. Don't start thread just yet
@@ -262,31 +840,900 @@ class AstroTuxLauncher():
         # Initialize thread for sending status updates to endpoint
         self.status_thread = interface.StatusUpdaterThread(self.config.status.EndpointURL, timeout=self.config.status.Interval, status=False)
         
-        # Initialize notification objects
-        self.notifications = interface.NotificationManager()
+        # Initialize notification objects. The event history is kept, when the notification manager is recreated
+        self.event_history = interface.EventHistoryHandler(self.config.EventHistorySize, name=self.config.notifications.name)
+        self.notifications = self.create_notification_manager(spool=not check_only)
         
-        self.notifications.add_handler(interface.LoggingNotificationHandler())
+        # Running the Dedicated Server as root is a security risk and creates files, that other users can't change later
+        if not (remote or check_only) and (os.geteuid() == 0) and not self.config.AllowRoot:
+            LOGGER.warning("#" * 60)
+            LOGGER.warning(f"{NAME} is running as root! This is a security risk and can leave files, that only root can change")
+            LOGGER.warning("Consider running it as a dedicated user instead. Set the 'AllowRoot' config option to hide this warning")
+            LOGGER.warning("#" * 60)
+            
+            self.notifications.send_event(interface.EventType.MESSAGE, message=f"{NAME} is running as root, consider running it as a dedicated user")
+        
+        # Create Dedicated Server object, if the server is running locally
+        if not (remote or check_only):
+            self.dedicatedserver = AstroDedicatedServer(self)
+    
+    def apply_config_overrides(self, config):
+        """
+            Applies the overrides passed as CLI parameters to the given launcher {config} and makes paths absolute
+            
+            Returns: The config with the overrides applied
+        """
+        
+        # If cli parameter is specified, it overrides the config value
+        if not (self.astro_path_override is None):
+            config = dataclasses.replace(config, AstroServerPath=self.astro_path_override)
+        
+        if not (self.server_port_override is None):
+            config.OverrideServerPort = self.server_port_override
+        
+        if not (self.console_port_override is None):
+            config.OverrideConsolePort = self.console_port_override
+        
+        if not (self.wine_prefix_override is None):
+            config.WinePrefixPath = self.wine_prefix_override
+        
+        if not (self.wine_path_override is None):
+            config.OverrideWinePath = self.wine_path_override
+        
+        # If flag was passed, overrule config option
+        if self.force_debug_log:
+            config.LogDebugMessages = True
+        
+        if self.no_file_log:
+            config.DisableFileLog = True
+        
+        if not (self.log_format_override is None):
+            config.ConsoleLogFormat = self.log_format_override
+        
+        # Make sure we use absolute paths
+        config.AstroServerPath = path.abspath(config.AstroServerPath)
+        config.WinePrefixPath = path.abspath(config.WinePrefixPath)
+        config.DepotDownloaderPath = path.abspath(config.DepotDownloaderPath)
+        config.LogPath = path.abspath(config.LogPath)
+        config.backup.BackupPath = path.abspath(config.backup.BackupPath)
+        
+        if config.PIDFile is not None:
+            config.PIDFile = path.abspath(config.PIDFile)
+        
+        return config
+    
+    def create_notification_handler(self):
+        """
+            Creates the handler for the external notification method configured in the launcher config
+            
+            Returns: The notification handler or None, if no method is configured or its settings are incomplete
+        """
+        
+        handler = None
+        
+        # Entries for event types, that don't exist, and invalid values are ignored
+        event_types = [e.value for e in interface.EventType]
         
         if self.config.notifications.method == NotificationMethod.DISCORD:
             if self.config.notifications.discord.webhookURL:
-                self.notifications.add_handler(interface.DiscordNotificationHandler(self.config.notifications.discord.webhookURL, name=self.config.notifications.name, event_whitelist=set(self.config.notifications.EventWhitelist)))
+                handler = interface.DiscordNotificationHandler(self.config.notifications.discord.webhookURL, colors={interface.EventType(k): v for k, v in self.config.notifications.discord.colors.items() if (k in event_types) and isinstance(v, int) and (0 <= v <= 0xFFFFFF)}, thumbnail_url=self.config.notifications.discord.thumbnailURL, author_icon_url=self.config.notifications.discord.authorIconURL, silent_level=interface.NOTIFICATION_LEVELS[self.config.notifications.discord.silentBelowLevel], name=self.config.notifications.name, event_whitelist=set(self.config.notifications.EventWhitelist), timeout=self.config.notifications.Timeout)
             else:
                 LOGGER.warning("Discord Webhook URL is not set in config, not sending Discord notifications")
         elif self.config.notifications.method == NotificationMethod.NTFY:
             if self.config.notifications.ntfy.topic:
-                self.notifications.add_handler(interface.NTFYNotificationHandler(self.config.notifications.ntfy.topic, ntfy_url=self.config.notifications.ntfy.serverURL, name=self.config.notifications.name, event_whitelist=set(self.config.notifications.EventWhitelist)))
+                handler = interface.NTFYNotificationHandler(self.config.notifications.ntfy.topic, ntfy_url=self.config.notifications.ntfy.serverURL, click={interface.EventType(k): v for k, v in self.config.notifications.ntfy.click.items() if k in event_types}, actions={interface.EventType(k): v for k, v in self.config.notifications.ntfy.actions.items() if k in event_types}, priorities={interface.EventType(k): v for k, v in self.config.notifications.ntfy.priorities.items() if (k in event_types) and isinstance(v, int) and (1 <= v <= 5)}, topics={interface.EventType(k): v for k, v in self.config.notifications.ntfy.topics.items() if k in event_types}, level_topics={interface.NOTIFICATION_LEVELS[k]: v for k, v in self.config.notifications.ntfy.topics.items() if k in interface.NOTIFICATION_LEVELS}, name=self.config.notifications.name, event_whitelist=set(self.config.notifications.EventWhitelist), timeout=self.config.notifications.Timeout)
             else:
                 LOGGER.warning("ntfy topic is not set in config, not sending ntfy notifications")
+        elif self.config.notifications.method == NotificationMethod.GOTIFY:
+            gotify = self.config.notifications.gotify
+            
+            if gotify and gotify.token and gotify.serverURL:
+                handler = interface.GotifyNotificationHandler(gotify.token, gotify.serverURL, name=self.config.notifications.name, event_whitelist=set(self.config.notifications.EventWhitelist), timeout=self.config.notifications.Timeout)
+            else:
+                LOGGER.warning("Gotify token or server URL is not set in config, not sending Gotify notifications")
+        elif self.config.notifications.method == NotificationMethod.MATRIX:
+            matrix = self.config.notifications.matrix
+            
+            if matrix and matrix.homeserverURL and matrix.accessToken and matrix.roomID:
+                handler = interface.MatrixNotificationHandler(matrix.homeserverURL, matrix.accessToken, matrix.roomID, name=self.config.notifications.name, event_whitelist=set(self.config.notifications.EventWhitelist), timeout=self.config.notifications.Timeout)
+            else:
+                LOGGER.warning("Matrix homeserver URL, access token or room ID is not set in config, not sending Matrix notifications")
+        elif self.config.notifications.method == NotificationMethod.APPRISE:
+            apprise = self.config.notifications.apprise
+            
+            if not (apprise and apprise.urls):
+                LOGGER.warning("No Apprise URLs are set in config, not sending Apprise notifications")
+            elif shutil.which(apprise.executable) is None:
+                LOGGER.warning(f"Apprise executable '{apprise.executable}' not found, not sending Apprise notifications")
+            else:
+                handler = interface.AppriseNotificationHandler(apprise.urls, apprise_exec=apprise.executable, name=self.config.notifications.name, event_whitelist=set(self.config.notifications.EventWhitelist), timeout=self.config.notifications.Timeout)
+        elif self.config.notifications.method == NotificationMethod.EMAIL:
+            email = self.config.notifications.email
+            
+            if email and email.smtpHost and email.sender and email.recipients:
+                handler = interface.EmailNotificationHandler(email.smtpHost, email.smtpPort, email.sender, email.recipients, username=email.username, password=email.password, security=email.security, name=self.config.notifications.name, event_whitelist=set(self.config.notifications.EventWhitelist), timeout=self.config.notifications.Timeout)
+            else:
+                LOGGER.warning("SMTP host, sender or recipients are not set in config, not sending email notifications")
+        
+        return handler
+    
+    def warn_debug_file_log(self):
+        """
+            Warns about the disk usage, if debug messages are written to the log file.
+            Writing them is still allowed, e.g. for tracking down a problem
+        """
+        
+        if not interface.LauncherLogging.logs_debug_to_file():
+            return
+        
+        LOGGER.warning(f"Debug messages are written to the log file. This creates large log files quickly on a busy server, check the free space in '{self.config.LogPath}' regularly")
+        
+        if self.config.KeepUncompressedLogs is None:
+            LOGGER.warning("Consider setting KeepUncompressedLogs, such that old log files are compressed")
+    
+    def restart_on_event(self, trigger):
+        """ Passes a restart request caused by an event in RestartOnEvents on to the dedicated server, if it is managed by this launcher """
+        
+        if self.dedicatedserver is not None:
+            self.dedicatedserver.request_event_restart(trigger)
+    
+    def create_notification_manager(self, spool=True):
+        """
+            Creates a notification manager with the handlers configured in the launcher config
+            
+            Arguments:
+                - [spool]: Wether to use a spool file for the external notification method, if enabled in the config
+        """
+        
+        notifications = interface.NotificationManager()
+        
+        notifications.add_handler(interface.LoggingNotificationHandler(logger_name=self.config.notifications.EventLoggerName))
+        notifications.add_handler(self.event_history)
+        
+        if len(self.config.RestartOnEvents) > 0:
+            notifications.add_handler(interface.EventTriggerHandler(self.config.RestartOnEvents, self.restart_on_event))
+        
+        try:
+            handler = self.create_notification_handler()
+        except Exception as e:
+            # Losing notifications is better than not running the server at all
+            LOGGER.warning("#" * 60)
+            LOGGER.warning(f"Could not set up {self.config.notifications.method.value} notifications ({type(e).__name__}): {str(e)}")
+            LOGGER.warning("Continuing without sending notifications! Check the notification settings")
+            LOGGER.warning("#" * 60)
+            handler = None
+        
+        if handler is not None:
+            handler.set_retries(self.config.notifications.Retries)
+            
+            level = getattr(self.config.notifications, self.config.notifications.method.value).level
+            
+            if level is not None:
+                handler.set_level(interface.NOTIFICATION_LEVELS[level])
+            
+            event_types = [e.value for e in interface.EventType]
+            handler.set_templates({interface.EventType(k): v for k, v in self.config.notifications.templates.items() if (k in event_types) and interface.valid_format(v)})
+            
+            if spool and self.config.notifications.SpoolNotifications:
+                try:
+                    handler.set_spool_file(path.join(self.config.LogPath, f"notifications_{self.config.notifications.method.value}.spool"))
+                except Exception as e:
+                    LOGGER.error(f"Could not use notification spool file: {str(e)}")
+            
+            notifications.add_handler(handler)
+        
+        return notifications
+    
+    def reload_config(self):
+        """
+            Reads the configuration file again and applies the changes, that can be applied while running.
+            Changes to options in RESTART_REQUIRED_OPTIONS are ignored
+        """
+        
+        LOGGER.info("Reloading configuration file...")
+        
+        try:
+            new_config = self.apply_config_overrides(LauncherConfig.ensure_config(self.config_path, self.profile))
+        except Exception as e:
+            LOGGER.error(f"Error while reloading config file ({type(e).__name__}): {str(e)}")
+            LOGGER.warning("Keeping current configuration")
+            return
+        
+        problems = new_config.validate()
+        
+        if len(problems) > 0:
+            LOGGER.error("The configuration file contains invalid values:")
+            
+            for problem in problems:
+                LOGGER.error(f"    - {problem}")
+            
+            LOGGER.warning("Keeping current configuration")
+            return
+        
+        for warning in new_config.find_warnings():
+            LOGGER.warning(f"Configuration: {warning}")
+        
+        for option in RESTART_REQUIRED_OPTIONS:
+            if getattr(new_config, option) != getattr(self.config, option):
+                LOGGER.warning(f"Change of config option '{option}' ignored, requires restart")
+                setattr(new_config, option, getattr(self.config, option))
+        
+        # Keep current Playfab API interval, as it might be increased temporarily while backing off
+        new_config.PlayfabAPIInterval = self.config.PlayfabAPIInterval
+        
+        self.config = new_config
+        
+        # Apply changes to components, that don't read the config directly
+        logged_debug = interface.LauncherLogging.logs_debug_to_file()
+        interface.LauncherLogging.set_log_debug(self.config.LogDebugMessages)
+        interface.LauncherLogging.set_console_format(self.config.ConsoleLogFormat)
+        
+        # Only warn again, if debug messages have just been enabled for the log file
+        if not logged_debug:
+            self.warn_debug_file_log()
+        
+        self.event_history.set_size(self.config.EventHistorySize)
+        self.event_history.name = self.config.notifications.name
+        
+        # Stop the handlers of the old config first, such that they don't keep sending events using the old settings
+        self.notifications.stop(timeout=self.config.notifications.Timeout)
+        self.notifications = self.create_notification_manager()
+        
+        for server in [self.dedicatedserver, self.remoteserver]:
+            if server:
+                server.rcon.set_timeout(self.config.ConsoleCommandTimeout)
+                server.rcon.set_keepalive(self.config.ConsoleKeepAlive)
+                server.rcon.set_command_names(get_command_names(self.config.ConsoleCommandNames))
+        
+        LOGGER.info("Configuration reloaded")
+    
+    def acquire_pid_file(self):
+        """
+            Writes the PID of the launcher to the configured PID file, which is removed again on exit.
+            
+            Returns: A boolean indicating the success, which is False if the PID file belongs to another running process
+        """
+        
+        pid_path = self.config.PIDFile
+        
+        if path.isfile(pid_path):
+            try:
+                with open(pid_path, "r") as pf:
+                    other_pid = int(pf.read().strip())
+            except (OSError, ValueError):
+                other_pid = None
+            
+            if (other_pid is not None) and (other_pid != os.getpid()) and psutil.pid_exists(other_pid):
+                return False
+            
+            LOGGER.debug(f"Replacing stale PID file (PID: {other_pid})")
+        
+        os.makedirs(path.dirname(pid_path), exist_ok=True)
+        
+        with open(pid_path, "w") as pf:
+            pf.write(str(os.getpid()))
+        
+        atexit.register(self.remove_pid_file)
+        
+        return True
+    
+    def remove_pid_file(self):
+        """ Removes the PID file, if it still belongs to this launcher """
+        
+        try:
+            with open(self.config.PIDFile, "r") as pf:
+                if int(pf.read().strip()) != os.getpid():
+                    return
+            
+            os.remove(self.config.PIDFile)
+        except (OSError, ValueError):
+            pass
+    
+    def check_config(self):
+        """
+            Checks the configuration, the Astroneer Dedicated Server installation and the environment and logs a summary
+            
+            Returns: A boolean indicating, wether no problems were found
+        """
+        
+        problems = list(self.config_problems)
+        
+        LOGGER.info("Configuration check summary:")
+        LOGGER.info(f"    - Configuration values: {'valid' if len(self.config_problems) == 0 else 'invalid'}{f' ({len(self.config_warnings)} warning(s))' if len(self.config_warnings) > 0 else ''}")
+        
+        # Check installation of the Astroneer Dedicated Server
+        build_version = read_build_version(self.config.AstroServerPath)
+        
+        integrity_problems = self.check_ds_integrity()
+        
+        if (build_version is not None) and self.check_ds_executable() and (len(integrity_problems) > 0):
+            LOGGER.info(f"    - Dedicated Server: incomplete (v{build_version}) in {self.config.AstroServerPath}")
+            problems.append(f"AstroServerPath: The Astroneer Dedicated Server installation is incomplete: {', '.join(integrity_problems)}. Use the 'update' command to repair it")
+        elif (build_version is not None) and self.check_ds_executable():
+            LOGGER.info(f"    - Dedicated Server: installed (v{build_version}) in {self.config.AstroServerPath}")
+        else:
+            LOGGER.info(f"    - Dedicated Server: not installed in {self.config.AstroServerPath}")
+            problems.append(f"AstroServerPath: No Astroneer Dedicated Server installation found in '{self.config.AstroServerPath}'")
+        
+        # Check wine executables
+        if not self.config.UseWine:
+            LOGGER.info("    - Wine: not used")
+        elif (self.wineexec is not None) and (self.wineserverexec is not None) and path.isfile(self.wineserverexec):
+            LOGGER.info(f"    - Wine: {self.wineexec}")
+        else:
+            LOGGER.info("    - Wine: not found")
+            problems.append("OverrideWinePath: Wine (or Wineserver) executable not found")
+        
+        # Check notification settings
+        method = self.config.notifications.method
+        
+        if method == NotificationMethod.NONE:
+            LOGGER.info("    - Notifications: disabled")
+        elif method == NotificationMethod.DISCORD:
+            configured = (self.config.notifications.discord is not None) and bool(self.config.notifications.discord.webhookURL)
+            LOGGER.info(f"    - Notifications: discord ({'configured' if configured else 'not configured'})")
+            
+            if not configured:
+                problems.append("notifications.discord.webhookURL: Has to be set, if method is 'discord'")
+        elif method == NotificationMethod.NTFY:
+            configured = (self.config.notifications.ntfy is not None) and bool(self.config.notifications.ntfy.topic)
+            LOGGER.info(f"    - Notifications: ntfy ({'configured' if configured else 'not configured'})")
+            
+            if not configured:
+                problems.append("notifications.ntfy.topic: Has to be set, if method is 'ntfy'")
+        elif method == NotificationMethod.GOTIFY:
+            configured = (self.config.notifications.gotify is not None) and bool(self.config.notifications.gotify.token) and bool(self.config.notifications.gotify.serverURL)
+            LOGGER.info(f"    - Notifications: gotify ({'configured' if configured else 'not configured'})")
+            
+            if not configured:
+                problems.append("notifications.gotify: token and serverURL have to be set, if method is 'gotify'")
+        elif method == NotificationMethod.MATRIX:
+            matrix = self.config.notifications.matrix
+            configured = (matrix is not None) and bool(matrix.homeserverURL) and bool(matrix.accessToken) and bool(matrix.roomID)
+            LOGGER.info(f"    - Notifications: matrix ({'configured' if configured else 'not configured'})")
+            
+            if not configured:
+                problems.append("notifications.matrix: homeserverURL, accessToken and roomID have to be set, if method is 'matrix'")
+        elif method == NotificationMethod.APPRISE:
+            apprise = self.config.notifications.apprise
+            configured = (apprise is not None) and (len(apprise.urls) > 0)
+            LOGGER.info(f"    - Notifications: apprise ({'configured' if configured else 'not configured'})")
+            
+            if not configured:
+                problems.append("notifications.apprise.urls: Has to contain at least one URL, if method is 'apprise'")
+            elif shutil.which(apprise.executable) is None:
+                problems.append(f"notifications.apprise.executable: '{apprise.executable}' not found")
+        elif method == NotificationMethod.EMAIL:
+            email = self.config.notifications.email
+            configured = (email is not None) and bool(email.smtpHost) and bool(email.sender) and (len(email.recipients) > 0)
+            LOGGER.info(f"    - Notifications: email ({'configured' if configured else 'not configured'})")
+            
+            if not configured:
+                problems.append("notifications.email: smtpHost, sender and recipients have to be set, if method is 'email'")
+        
+        LOGGER.info(f"    - Status updates: {'enabled' if self.config.status.SendStatus else 'disabled'}")
+        
+        # Check that logs can be written
+        if self.config.DisableFileLog:
+            LOGGER.info("    - Log directory: file logging disabled")
+        elif os.access(self.config.LogPath, os.W_OK):
+            LOGGER.info(f"    - Log directory: {self.config.LogPath} (writable)")
+        else:
+            LOGGER.info(f"    - Log directory: {self.config.LogPath} (not writable)")
+            problems.append(f"LogPath: The log directory '{self.config.LogPath}' is not writable")
+        
+        if len(problems) > 0:
+            LOGGER.error(f"Found {len(problems)} problem(s):")
+            
+            for problem in problems:
+                LOGGER.error(f"    - {problem}")
+            
+            return False
+        
+        LOGGER.info("No problems found")
+        return True
+    
+    def migrate_config(self, from_path):
+        """
+            Imports the settings from the launcher config file at {from_path} into the config file of the launcher.
+            Options, that don't exist anymore, are reported and left out. The previous config file is kept as '<config>.bak'
+            
+            Returns: A boolean indicating the success
+        """
+        
+        from_path = path.abspath(from_path)
+        
+        LOGGER.info(f"Migrating configuration from '{from_path}'...")
+        
+        try:
+            old_config_dict = LauncherConfig.read_config_file(from_path)
+            
+            for key in LauncherConfig.find_unknown_keys(old_config_dict):
+                LOGGER.warning(f"Config option '{key}' has no equivalent and is ignored")
+            
+            config = LauncherConfig.from_dict(old_config_dict)
+        except Exception as e:
+            LOGGER.error(f"Error while reading config file to migrate ({type(e).__name__}): {str(e)}")
+            return False
+        
+        for problem in config.validate() + config.find_warnings():
+            LOGGER.warning(f"Invalid value in migrated configuration: {problem}")
+        
+        if path.isfile(self.config_path):
+            backup_path = f"{self.config_path}.bak"
+            
+            try:
+                shutil.copy2(self.config_path, backup_path)
+            except Exception as e:
+                LOGGER.error(f"Error while backing up config file to '{backup_path}' ({type(e).__name__}): {str(e)}")
+                return False
+            
+            LOGGER.info(f"Backed up previous configuration to '{backup_path}'")
+        
+        try:
+            config.write_config_file(self.config_path)
+        except Exception as e:
+            LOGGER.error(f"Error while writing config file ({type(e).__name__}): {str(e)}")
+            return False
+        
+        LOGGER.info(f"Migrated configuration to '{self.config_path}'")
+        return True
+    
+    def get_status(self):
+        """
+            Gathers information about the Astroneer Dedicated Server installation, the WINE prefix and the running server, if it is reachable over the console port
+            
+            Returns: A dictionary containing the information
+        """
+        
+        build_version = read_build_version(self.config.AstroServerPath)
+        
+        status = {
+            "installed": (build_version is not None) and self.check_ds_executable(),
+            "install_path": self.config.AstroServerPath,
+            "build_version": build_version,
+            "wine_prefix": self.config.WinePrefixPath,
+            "wine_prefix_valid": self.check_wine_prefix(),
+            "clock_skew": get_clock_skew(self.config.AstroServerPath),
+            "server": None
+        }
+        
+        rcon = self.connect_local_console()
+        
+        if rcon is None:
+            return status
+        
+        try:
+            stats = rcon.DSServerStatistics()
+            players = rcon.DSListPlayers()
+            
+            if isinstance(stats, dict):
+                status["server"] = stats
+                
+                if isinstance(players, dict):
+                    status["server"]["playersOnline"] = len([pi for pi in players.get("playerInfo", []) if pi.get("inGame")])
+        except Exception as e:
+            LOGGER.debug(f"Could not get information from the Dedicated Server: {str(e)}")
+        finally:
+            rcon.disconnect()
+        
+        return status
+    
+    def connect_local_console(self):
+        """
+            Connects to the console port of the local Dedicated Server, e.g. one managed by another launcher process.
+            The console port and password are read from the config files without changing them
+            
+            Returns: The connected AstroRCON object or None, if the Dedicated Server is not reachable
+        """
+        
+        ds_config = self.read_ds_config()
+        
+        if ds_config is None:
+            return None
+        
+        console_port = self.config.OverrideConsolePort if self.config.OverrideConsolePort is not None else ds_config.ConsolePort
+        rcon = AstroRCON(console_port, ds_config.ConsolePassword, timeout=self.config.ConsoleCommandTimeout, keepalive=self.config.ConsoleKeepAlive, command_names=get_command_names(self.config.ConsoleCommandNames))
+        
+        try:
+            rcon.connect(timeout=self.config.ConsoleCommandTimeout)
+        except ConsoleError as e:
+            LOGGER.debug(f"Dedicated Server is not reachable on console port {console_port}: {str(e)}")
+            rcon.disconnect()
+            return None
+        
+        return rcon
+    
+    def read_ds_config(self):
+        """
+            Reads the configuration file of the Dedicated Server without changing it
+            
+            Returns: The config or None, if it couldn't be read
+        """
+        
+        try:
+            return DedicatedServerConfig.read_config(path.join(self.config.AstroServerPath, ASTRO_DS_CONFIG_PATH, "AstroServerSettings.ini"))
+        except Exception as e:
+            LOGGER.debug(f"Could not read Dedicated Server config: {str(e)}")
+            return None
+    
+    def check_health(self):
+        """
+            Checks, wether the Dedicated Server answers on the console port and is registered with Playfab
+            
+            Returns: A boolean indicating, wether the Dedicated Server is healthy
+        """
+        
+        ds_config = self.read_ds_config()
+        
+        if ds_config is None:
+            LOGGER.error("Unhealthy: Dedicated Server config not found")
+            return False
+        
+        console_port = self.config.OverrideConsolePort if self.config.OverrideConsolePort is not None else ds_config.ConsolePort
+        rcon = AstroRCON(console_port, ds_config.ConsolePassword, timeout=self.config.ConsoleCommandTimeout, keepalive=self.config.ConsoleKeepAlive, command_names=get_command_names(self.config.ConsoleCommandNames))
+        
+        try:
+            rcon.connect(timeout=self.config.ConsoleCommandTimeout)
+            
+            if not isinstance(rcon.DSServerStatistics(), dict):
+                LOGGER.error("Unhealthy: Dedicated Server didn't answer on the console port" + (f": {rcon.last_error.describe()}" if rcon.last_error else ""))
+                return False
+        except Exception as e:
+            LOGGER.error(f"Unhealthy: Dedicated Server is not reachable on console port {console_port}: {str(e)}")
+            return False
+        finally:
+            rcon.disconnect()
+        
+        # Check registration with the same IP/Port combination the Dedicated Server registers with
+        server_port = self.config.OverrideServerPort
+        
+        if server_port is None:
+            try:
+                engine_config = EngineConfig.read_config(path.join(self.config.AstroServerPath, ASTRO_DS_CONFIG_PATH, "Engine.ini"))
+            except Exception as e:
+                LOGGER.debug(f"Could not read Engine config: {str(e)}")
+                engine_config = None
+            
+            server_port = engine_config.Port if engine_config is not None else EngineConfig.Port
+        
+        try:
+            response = playfab.get_server(f"{ds_config.PublicIP}:{server_port}", playfab.generate_XAuth(ds_config.ServerGuid))
+        except Exception as e:
+            LOGGER.error(f"Unhealthy: Could not check registration with Playfab: {str(e)}")
+            return False
+        
+        if (response["status"] != "OK") or (len(response["data"]["Games"]) == 0):
+            LOGGER.error("Unhealthy: Dedicated Server is not registered with Playfab")
+            return False
+        
+        print("healthy")
+        return True
+    
+    def get_wine_version(self):
+        """
+            Asks the wine executable for its version
+            
+            Returns: The version string or None, if it couldn't be determined
+        """
+        
+        if self.wineexec is None:
+            return None
+        
+        try:
+            proc = subprocess.run([self.wineexec, "--version"], capture_output=True, text=True, timeout=10)
+            return proc.stdout.strip() or None
+        except Exception as e:
+            LOGGER.debug(f"Could not get WINE version: {str(e)}")
+            return None
+    
+    def get_versions(self):
+        """
+            Gathers the versions of the launcher and the other components and the paths they were found at
+            
+            Returns: A dictionary containing the information, where versions, that couldn't be determined, are None
+        """
+        
+        return {
+            "launcher": LAUNCHER_VERSION,
+            "server": read_build_version(self.config.AstroServerPath),
+            "server_path": self.config.AstroServerPath,
+            "wine": self.get_wine_version(),
+            "wine_path": self.wineexec,
+            "python": platform.python_version(),
+            "system": platform.platform(),
+            "config_path": self.config_path
+        }
+    
+    def show_version(self, full=False):
+        """
+            Prints the version of the launcher and, if {full} is set, also the versions of the other components and the config path
+        """
+        
+        print(f"{NAME} v{LAUNCHER_VERSION}")
+        
+        if not full:
+            return
+        
+        versions = self.get_versions()
+        
+        print(f"Dedicated Server: {versions['server'] if versions['server'] else 'not installed'} ({versions['server_path']})")
+        
+        if versions["wine_path"] is None:
+            print("WINE: not found")
+        else:
+            print(f"WINE: {versions['wine'] or 'unknown version'} ({versions['wine_path']})")
+        
+        print(f"Python: {versions['python']}")
+        print(f"System: {versions['system']}")
+        print(f"Config file: {versions['config_path']}")
+    
+    def format_config(self, as_json=False):
+        """
+            Formats the effective launcher configuration including the overrides, with the values of secrets redacted
+            
+            Arguments:
+                - [as_json]: Wether to format the configuration as JSON instead of TOML
+            
+            Returns: The formatted configuration as a string
+        """
+        
+        config_dict = redact_secrets(self.config.to_dict(encode_json=True))
+        
+        if as_json:
+            return json.dumps(config_dict, indent=4) + "\n"
+        
+        header = f"# Effective configuration of {self.config_path}" + (f" (Profile: {self.profile})" if self.profile else "")
+        return header + "\n" + tomli_w.dumps({"launcher": config_dict})
+    
+    def show_config(self, as_json=False):
+        """
+            Prints the effective launcher configuration including the overrides, with the values of secrets redacted
+            
+            Arguments:
+                - [as_json]: Wether to print the configuration as JSON instead of TOML
+        """
+        
+        print(self.format_config(as_json), end="")
+    
+    def create_report(self, report_dir):
+        """
+            Bundles the effective configuration with secrets redacted, the launcher and server log files of the last day,
+            the versions of the components and information about the installation into a zip file, that can be attached to a bug report
+            
+            Arguments:
+                - report_dir: The directory to create the zip file in
+            
+            Returns: A boolean indicating, wether the report could be created
+        """
+        
+        report_path = path.join(report_dir, f"astrotux_report_{time.strftime('%Y-%m-%d_%H-%M-%S')}.zip")
+        
+        try:
+            log_files = [path.join(self.config.LogPath, f) for f in os.listdir(self.config.LogPath) if f.startswith(("astrotux_", SERVER_LOG_BASE_FILENAME + "_"))]
+            log_files = [f for f in log_files if path.isfile(f) and ((time.time() - path.getmtime(f)) < REPORT_LOG_MAX_AGE)]
+        except OSError as e:
+            LOGGER.warning(f"Could not read log directory '{self.config.LogPath}', the report doesn't include log files: {str(e)}")
+            log_files = []
+        
+        info = {
+            "versions": self.get_versions(),
+            "status": self.get_status(),
+            "config_problems": self.config_problems,
+            "config_warnings": self.config_warnings
+        }
+        
+        try:
+            with zipfile.ZipFile(report_path, "w", compression=zipfile.ZIP_DEFLATED) as report:
+                report.writestr("config.toml", self.format_config())
+                report.writestr("info.json", json.dumps(info, indent=4, default=str))
+                
+                for log_file in sorted(log_files):
+                    report.write(log_file, arcname=path.join("logs", path.basename(log_file)))
+        except OSError as e:
+            LOGGER.error(f"Could not write report to '{report_path}': {str(e)}")
+            return False
+        
+        LOGGER.info(f"Report written to '{report_path}' ({len(log_files)} log files)")
+        LOGGER.info("The log files are included as-is, so check them for information you don't want to share, like player names and IP addresses, before attaching the report")
+        return True
+    
+    def show_status(self, as_json=False):
+        """
+            Prints information about the Astroneer Dedicated Server installation and the running server
+            
+            Arguments:
+                - [as_json]: Wether to print the information as JSON instead of a human readable summary
+        """
+        
+        status = self.get_status()
+        
+        if as_json:
+            print(json.dumps(status, indent=4))
+            return
+        
+        LOGGER.info("Status:")
+        
+        if status["installed"]:
+            LOGGER.info(f"    - Dedicated Server: installed (v{status['build_version']}) in {status['install_path']}")
+        else:
+            LOGGER.info(f"    - Dedicated Server: not installed in {status['install_path']}")
+        
+        LOGGER.info(f"    - WINE prefix: {status['wine_prefix']} ({'valid' if status['wine_prefix_valid'] else 'not initialized'})")
+        
+        server = status["server"]
+        
+        if server is None:
+            LOGGER.info("    - Server: not reachable")
+        else:
+            LOGGER.info(f"    - Server: running (v{server.get('build')})")
+            LOGGER.info(f"        - Name: {server.get('serverName')}")
+            LOGGER.info(f"        - Players: {server.get('playersOnline', '?')}/{server.get('maxInGamePlayers')}")
+            LOGGER.info(f"        - Savegame: {server.get('saveGameName')}")
+            LOGGER.info(f"        - Average FPS: {server.get('averageFPS')}")
+        
+        if status["clock_skew"] is not None:
+            LOGGER.info(f"    - Clock difference to server logs: {status['clock_skew']} seconds")
+    
+    def watch_players(self, host, port, password=None):
+        """
+            Shows the online players of the Dedicated Server at {host}:{port} and refreshes the list in place every {ServerStatusInterval} seconds.
+            Players, that joined or left recently, are highlighted
+            
+            Returns: A boolean indicating, wether the server could be watched without losing the connection
+        """
+        
+        rcon = AstroRCON(port, password, ip=host, timeout=self.config.ConsoleCommandTimeout, keepalive=self.config.ConsoleKeepAlive, command_names=get_command_names(self.config.ConsoleCommandNames))
+        
+        try:
+            rcon.connect(timeout=self.config.ConsoleCommandTimeout)
+        except ConsoleError as e:
+            LOGGER.error(f"Could not connect to {host}:{port}: {e.describe()}")
+            rcon.disconnect()
+            return False
+        
+        prev_players = None
+        changes = {}    # Maps GUID to (player info, "joined"/"left", time of change)
+        
+        try:
+            while True:
+                res = rcon.DSListPlayers()
+                
+                if not isinstance(res, dict):
+                    LOGGER.error(f"Lost connection to {host}:{port}" + (f": {rcon.last_error.describe()}" if rcon.last_error else ""))
+                    return False
+                
+                players = {pi.playerGuid: pi for pi in PlayerList.from_dict(res).playerInfo if pi.inGame}
+                now = time.time()
+                
+                if prev_players is not None:
+                    for guid in set(players) - set(prev_players):
+                        changes[guid] = (players[guid], "joined", now)
+                    
+                    for guid in set(prev_players) - set(players):
+                        changes[guid] = (prev_players[guid], "left", now)
+                
+                # Only highlight recent changes
+                changes = {guid: change for guid, change in changes.items() if (now - change[2]) < WATCH_HIGHLIGHT_TIME}
+                
+                rows = []
+                
+                for guid, pi in players.items():
+                    status = interface.ConsoleOutput.colorize("joined", ansi.GREEN) if (guid in changes) and (changes[guid][1] == "joined") else "online"
+                    rows.append([pi.playerName, pi.playerGuid, pi.playerCategory.value, status])
+                
+                for guid, (pi, change, _) in changes.items():
+                    if change == "left":
+                        rows.append([pi.playerName, pi.playerGuid, pi.playerCategory.value, interface.ConsoleOutput.colorize("left", ansi.RED)])
+                
+                if CONTROL_CODES_SUPPORTED is not False:
+                    ANSI.clear_screen()
+                    ANSI.home()
+                
+                print(f"Players on {host}:{port} ({len(players)} online, updated {time.strftime('%H:%M:%S')}). Press Ctrl+C to exit")
+                print("")
+                
+                for line in interface.ConsoleOutput.format_table(["Name", "GUID", "Category", "Status"], rows, indent=0):
+                    print(line)
+                
+                prev_players = players
+                time.sleep(self.config.ServerStatusInterval)
+        except ConsoleError as e:
+            LOGGER.error(f"Lost connection to {host}:{port}: {e.describe()}")
+            return False
+        finally:
+            rcon.disconnect()
+    
+    def show_logs(self, lines=50, follow=False, log_file=None):
+        """
+            Prints the last lines of a launcher log file. Compressed (.gz) log files are read transparently
+            
+            Arguments:
+                - [lines]: The number of lines to print
+                - [follow]: Wether to keep printing new lines as they are written to the log file
+                - [log_file]: Name or path of the log file to show. If not given, the newest log file is shown
+            
+            Returns: A boolean indicating, wether the log file could be read
+        """
+        
+        if log_file is None:
+            try:
+                log_files = [path.join(self.config.LogPath, f) for f in os.listdir(self.config.LogPath) if f.startswith("astrotux_") and (f.endswith(".log") or f.endswith(".log.gz"))]
+            except OSError as e:
+                LOGGER.error(f"Could not read log directory '{self.config.LogPath}': {str(e)}")
+                return False
+            
+            if len(log_files) == 0:
+                LOGGER.error(f"No log files found in '{self.config.LogPath}'")
+                return False
+            
+            log_file = max(log_files, key=path.getmtime)
+        elif not path.isfile(log_file):
+            # Also allow only the name of a file in the log directory
+            log_file = path.join(self.config.LogPath, log_file)
+        
+        if not path.isfile(log_file):
+            LOGGER.error(f"Log file '{log_file}' not found")
+            return False
+        
+        LOGGER.info(f"Showing log file '{log_file}':")
         
-        # Create Dedicated Server object
-        self.dedicatedserver = AstroDedicatedServer(self)
+        compressed = log_file.endswith(".gz")
+        
+        with (gzip.open(log_file, "rt") if compressed else open(log_file, "r")) as lf:
+            for line in collections.deque(lf, maxlen=lines):
+                print(line, end="")
+            
+            if follow and compressed:
+                LOGGER.warning("Following compressed log files is not possible")
+            elif follow:
+                while True:
+                    line = lf.readline()
+                    
+                    if line:
+                        print(line, end="", flush=True)
+                    else:
+                        time.sleep(0.5)
+        
+        return True
+    
+    def get_ds_executable(self):
+        """ Returns the path of the Astroneer DS executable, which is the native one, if WINE is not used """
+        
+        return os.path.join(self.config.AstroServerPath, DS_EXECUTABLE if self.config.UseWine else DS_NATIVE_EXECUTABLE)
     
     def check_ds_executable(self):
         """ Checks is Astroneer DS executable exists and is a file """
         
-        execpath = os.path.join(self.config.AstroServerPath, DS_EXECUTABLE)
+        execpath = self.get_ds_executable()
         
         return os.path.exists(execpath) and os.path.isfile(execpath)
+    
+    def check_ds_integrity(self):
+        """
+            Checks, that the last installation/update of the Astroneer DS was not interrupted and that the required files exist and are not empty
+            
+            Returns: A list of messages describing the problems found, which is empty if the installation is complete
+        """
+        
+        problems = []
+        
+        if path.exists(path.join(self.config.AstroServerPath, DS_INSTALL_MARKER)):
+            problems.append("the last installation/update was interrupted")
+        
+        required_files = [self.get_ds_executable()]
+        
+        # The other files are only known for the Windows version
+        if self.config.UseWine:
+            required_files += [path.join(self.config.AstroServerPath, filename) for filename in DS_REQUIRED_FILES]
+        
+        for filepath in required_files:
+            if not path.isfile(filepath):
+                problems.append(f"'{path.relpath(filepath, self.config.AstroServerPath)}' is missing")
+            elif path.getsize(filepath) == 0:
+                problems.append(f"'{path.relpath(filepath, self.config.AstroServerPath)}' is empty")
+        
+        return problems
 
     def on_input(self, input_string):
         """ Callback method to handle console input """
@@ -337,21 +1784,101 @@ class AstroTuxLauncher():
         
         return code == 0    
     
+    def check_wine_prefix(self):
+        """ Checks that the WINE prefix exists and has been initialized """
+        
+        return path.isdir(self.config.WinePrefixPath) and path.isfile(path.join(self.config.WinePrefixPath, "system.reg"))
+    
+    def retry_network_test(self, test, *args):
+        """
+            Runs a network test and repeats it every NETWORK_CHECK_RETRY_DELAY seconds, until it succeeds or {NetworkCheckRetryTime} has passed
+            
+            Arguments:
+                - test: The function performing the test, which returns wether it succeeded or None, if the result is unknown
+                - *args: The arguments to pass to the test function
+            
+            Returns: Wether the test succeeded or None, if the result of the last try is unknown
+        """
+        
+        deadline = time.time() + self.config.NetworkCheckRetryTime
+        
+        result = test(*args)
+        
+        while not result:
+            if (time.time() + NETWORK_CHECK_RETRY_DELAY) > deadline:
+                return result
+            
+            LOGGER.debug(f"Network test failed, trying again in {NETWORK_CHECK_RETRY_DELAY} seconds...")
+            time.sleep(NETWORK_CHECK_RETRY_DELAY)
+            
+            result = test(*args)
+        
+        return True
+    
     def check_network_config(self):
+        """
+            Checks, that the server port is reachable and the RCON port isn't reachable from outside.
+            As the tests have to bind the ports themselves, this has to be done before starting the Dedicated Server.
+            If NetworkCheckTime is set to after_registration, only the RCON port is checked and check_server_reachable should be used after the registration
+        """
+        
         if not self.dedicatedserver:
             raise ValueError("Dedcated Server has to be created first")
         
         LOGGER.info("Checking Network Configuration...")
         
-        # Check if server port is reachable from local network over UDP
-        server_local_reachable = net.net_test_local(self.dedicatedserver.ds_config.PublicIP, self.dedicatedserver.engine_config.Port, False)
+        if self.config.NetworkCheckTime == NetworkCheckTiming.BEFORE_START:
+            self.report_server_reachability(
+                # Check if server port is reachable from local network over UDP
+                self.retry_network_test(net.net_test_local, self.dedicatedserver.ds_config.PublicIP, self.dedicatedserver.engine_config.Port, False),
+                # Check if server post is reachable from internet over UDP
+                self.retry_network_test(net.net_test_nonlocal, self.dedicatedserver.ds_config.PublicIP, self.dedicatedserver.engine_config.Port)
+            )
+        else:
+            LOGGER.info("The reachability of the server port is checked after the Dedicated Server has registered")
+        
+        rcon_local_blocked = not net.net_test_local(self.dedicatedserver.ds_config.PublicIP, self.dedicatedserver.ds_config.ConsolePort, True)
+        
+        if rcon_local_blocked:
+            LOGGER.info("RCON network configuration looks good")
+        else:
+            LOGGER.warning(f"SECURITY ALERT: The RCON Port ({self.dedicatedserver.ds_config.ConsolePort}) is accessible from outside")
+            LOGGER.warning("SECURITY ALERT: This potentially allows access to the Remote Console from outside your network")
+            LOGGER.warning("SECURITY ALERT: Disable this ASAP to prevent issues")
+            
+            # kept from AstroLauncher
+            time.sleep(5)
+    
+    def check_server_reachable(self):
+        """
+            Checks, that the running Dedicated Server is reachable from outside of the local network.
+            Testing from the local network is not possible anymore, as the server has bound the port already
+        """
         
-        # Check if server post is reachable from internet over UDP
-        server_nonlocal_reachable = net.net_test_nonlocal(self.dedicatedserver.ds_config.PublicIP, self.dedicatedserver.engine_config.Port)
+        LOGGER.info("Checking reachability of the Dedicated Server...")
+        
+        self.report_server_reachability(None, self.retry_network_test(net.net_test_server, self.dedicatedserver.ds_config.PublicIP, self.dedicatedserver.engine_config.Port))
+    
+    def report_server_reachability(self, local_reachable, nonlocal_reachable):
+        """
+            Logs the result of the reachability tests of the server port
+            
+            Arguments:
+                - local_reachable: Wether the server port is reachable from the local network or None, if that wasn't tested
+                - nonlocal_reachable: Wether the server port is reachable from outside of the local network or None, if that couldn't be checked
+        """
         
-        test_res = (server_local_reachable, server_nonlocal_reachable)
+        test_res = (local_reachable, nonlocal_reachable)
         
-        if test_res == (True, True):
+        if nonlocal_reachable is None:
+            LOGGER.warning("Connection to external service failed")
+            LOGGER.warning("Unable to verify connectivity from outside local network")
+        elif test_res == (None, True):
+            LOGGER.info("The Server is reachable from outside of the local network")
+        elif test_res == (None, False):
+            LOGGER.warning("The Server is not reachable from outside of the local network")
+            LOGGER.warning(f"Make sure the Server Port ({self.dedicatedserver.engine_config.Port}) is forwarded for UDP traffic and check firewall settings")
+        elif test_res == (True, True):
             LOGGER.info("Network configuration looks good")
         elif test_res == (False, True):
             LOGGER.warning("The Server is not accessible from the local network")
@@ -362,44 +1889,308 @@ class AstroTuxLauncher():
         elif test_res == (False, False):
             LOGGER.warning("The Server is completely unreachable")
             LOGGER.warning(f"Make sure the Server Port ({self.dedicatedserver.engine_config.Port}) is forwarded for UDP traffic and check firewall settings")
+    
+    def ensure_depotdownloader(self):
+        """
+            Makes sure, that the DepotDownloader executable is present and executable and downloads it, if it doesn't exist yet
+            
+            Returns: Wether DepotDownloader can be used
+        """
         
-        rcon_local_blocked = not net.net_test_local(self.dedicatedserver.ds_config.PublicIP, self.dedicatedserver.ds_config.ConsolePort, True)
+        if steam.is_executable(self.depotdl_path):
+            return True
         
-        if rcon_local_blocked:
-            LOGGER.info("RCON network configuration looks good")
-        else:
-            LOGGER.warning(f"SECURITY ALERT: The RCON Port ({self.dedicatedserver.ds_config.ConsolePort}) is accessible from outside")
-            LOGGER.warning("SECURITY ALERT: This potentially allows access to the Remote Console from outside your network")
-            LOGGER.warning("SECURITY ALERT: Disable this ASAP to prevent issues")
-            
-            # kept from AstroLauncher
-            time.sleep(5)
+        if path.exists(self.depotdl_path):
+            LOGGER.error(f"DepotDownloader at {self.depotdl_path} is not an executable file. Make it executable (chmod +x) or remove it, so it is downloaded again")
+            return False
+        
+        LOGGER.info(f"DepotDownloader not found at {self.depotdl_path}, downloading...")
+        
+        try:
+            steam.dl_depotdownloader(path.dirname(self.depotdl_path), path.basename(self.depotdl_path))
+        except Exception as e:
+            LOGGER.error(f"DepotDownloader not found at {self.depotdl_path} and automatic download failed: {type(e).__name__}: {str(e)}")
+            return False
+        
+        return True
     
     def update_server(self):
         """
             Installs/Updates the Astroneer Dedicated Server.
             Also ensures that DepotDownloader is present
+            
+            Returns: Wether the installation/update was successful
         """
         
-        # If DepotDownloader executable doesn't exists yet, download it
-        if not path.exists(self.depotdl_path):
-            LOGGER.info("DepotDownloader not found, downloading...")
-            steam.dl_depotdownloader(path.dirname(self.depotdl_path), path.basename(self.depotdl_path))
+        if not self.ensure_depotdownloader():
+            LOGGER.error("Can't install/update Astroneer Dedicated Server without DepotDownloader")
+            return False
+        
+        if not self.check_disk_space():
+            LOGGER.error("Not enough free disk space for installing/updating Astroneer Dedicated Server")
+            return False
+        
+        # Back up savegames before they could be affected by the update and don't risk losing them without a backup
+        if self.config.backup.BackupBeforeUpdate and (self.create_backup("update") is False):
+            LOGGER.error("Not installing/updating Astroneer Dedicated Server, as the savegames could not be backed up. Disable BackupBeforeUpdate to update anyway")
+            return False
+        
+        oldversion = read_build_version(self.config.AstroServerPath)
+        
+        # Mark the installation as incomplete until the update has finished, such that an interrupted update is repaired the next time
+        marker_path = path.join(self.config.AstroServerPath, DS_INSTALL_MARKER)
+        os.makedirs(self.config.AstroServerPath, exist_ok=True)
+        
+        with open(marker_path, "w"):
+            pass
         
         LOGGER.info("Updating Astroneer Dedicated Server app from Steam...")
-        success = steam.update_app(exec_path=self.depotdl_path, app="728470", os="windows", directory=self.config.AstroServerPath)
+        
+        # Without WINE the native Linux build of the Dedicated Server is needed
+        depot_os = "windows" if self.config.UseWine else "linux"
+        success = steam.update_app(exec_path=self.depotdl_path, app="728470", os=depot_os, directory=self.config.AstroServerPath)
         
         self.buildversion = read_build_version(self.config.AstroServerPath)
         
+        if success:
+            os.remove(marker_path)
+            
+            problems = self.check_ds_integrity()
+            
+            if len(problems) > 0:
+                LOGGER.error(f"The Astroneer Dedicated Server installation is incomplete after updating: {', '.join(problems)}")
+                self.notifications.send_event(interface.EventType.INSTALL_INCOMPLETE, problems=", ".join(problems), server_version=self.buildversion)
+                
+                # Make sure the next update installs the missing files again
+                with open(marker_path, "w"):
+                    pass
+                
+                return False
+        
         if success and (self.buildversion is not None):
             LOGGER.info(f"Sucessfully updated Astroneer Dedicated Server to version {self.buildversion}")
-        else:
-            LOGGER.error("Error while updating Astroneer Dedicated Server")
+            
+            # Only notify about actual updates, not about fresh installations
+            if (oldversion is not None) and (oldversion != self.buildversion):
+                self.notifications.send_event(interface.EventType.UPDATE_INSTALLED, old_version=oldversion, new_version=self.buildversion, server_version=self.buildversion)
+            
+            return True
+        
+        LOGGER.error("Error while updating Astroneer Dedicated Server")
+        return False
+    
+    def check_disk_space(self):
+        """
+            Checks, if the filesystem the Astroneer Dedicated Server is installed on has enough free disk space for installing/updating it
+            
+            Returns: A boolean indicating, if enough disk space is available
+        """
+        
+        if self.config.RequiredDiskSpace <= 0:
+            return True
+        
+        # The installation directory might not exist yet, so check the nearest existing parent directory
+        check_path = self.config.AstroServerPath
+        
+        while not path.exists(check_path):
+            check_path = path.dirname(check_path)
+        
+        try:
+            free_space = shutil.disk_usage(check_path).free / (1024 ** 3)
+        except OSError as e:
+            LOGGER.warning(f"Could not determine free disk space: {str(e)}")
+            return True
+        
+        LOGGER.debug(f"Free disk space: {free_space:.2f} GiB, required: {self.config.RequiredDiskSpace:.2f} GiB")
+        
+        if free_space < self.config.RequiredDiskSpace:
+            LOGGER.error(f"Only {free_space:.2f} GiB of free disk space available at '{check_path}', but {self.config.RequiredDiskSpace:.2f} GiB are required")
+            return False
+        
+        return True
+    
+    def create_backup(self, prefix, savegames_only=False):
+        """
+            Creates a backup of the Dedicated Server savegames (and configuration) and removes old backups with the same {prefix}
+            
+            Arguments:
+                - prefix: The prefix of the backup file name, which identifies the kind of backup
+                - [savegames_only]: Wether to only back up the savegames
+            
+            Returns: The path of the created backup, None if there was nothing to back up or False if creating the backup failed
+        """
+        
+        LOGGER.info("Creating backup of savegames...")
+        
+        try:
+            backup_file = backup.create_backup(self.config.AstroServerPath, self.config.backup.BackupPath, prefix=prefix, include_config=self.config.backup.IncludeConfig, savegames_only=savegames_only)
+        except Exception as e:
+            LOGGER.error(f"Error while creating backup: {str(e)}")
+            return False
+        
+        if backup_file is None:
+            LOGGER.info("Nothing to back up yet")
+            return None
+        
+        backup.remove_old_backups(self.config.backup.BackupPath, prefix=prefix, max_backups=self.config.backup.MaxBackups)
+        
+        self.notifications.send_event(interface.EventType.BACKUP_CREATED, backup_name=path.basename(backup_file), server_version=read_build_version(self.config.AstroServerPath))
+        
+        return backup_file
+    
+    def list_backups(self):
+        """ Shows the backups in the backup directory with their creation time and size """
+        
+        backups = backup.list_backups(self.config.backup.BackupPath)
+        
+        if len(backups) == 0:
+            LOGGER.info(f"No backups found in '{self.config.backup.BackupPath}'")
+            return
+        
+        LOGGER.info(f"Backups in '{self.config.backup.BackupPath}' (newest first):")
+        
+        rows = [[name, created.strftime("%Y-%m-%d %H:%M:%S"), f"{size / (1024 ** 2):.2f} MiB"] for name, created, size in backups]
+        
+        for line in interface.ConsoleOutput.format_table(["Name", "Created", "Size"], rows):
+            LOGGER.info(line)
+    
+    def create_manual_backup(self):
+        """
+            Creates a backup right away. If the Dedicated Server is running, the game is saved first, so the backup contains the current state
+            
+            Returns: A boolean indicating the success
+        """
+        
+        rcon = self.connect_local_console()
+        
+        if rcon is not None:
+            LOGGER.info("Saving game before creating backup...")
+            
+            try:
+                if rcon.DSSaveGame() is None:
+                    LOGGER.warning("Could not save the game, the backup contains the last saved state")
+            except ConsoleError as e:
+                LOGGER.warning(f"Could not save the game, the backup contains the last saved state: {e.describe()}")
+            finally:
+                rcon.disconnect()
+        
+        backup_file = self.create_backup("manual")
+        
+        if not backup_file:
+            return False
+        
+        LOGGER.info(f"Created backup '{path.basename(backup_file)}'")
+        return True
+    
+    def stop_local_server(self):
+        """
+            Saves the game and shuts down the local Dedicated Server, if it is running, and waits until it has shut down
+            
+            Returns: A boolean indicating, wether the Dedicated Server isn't running anymore
+        """
+        
+        rcon = self.connect_local_console()
+        
+        if rcon is None:
+            return True
+        
+        LOGGER.info("Saving game and shutting down the Dedicated Server...")
+        
+        try:
+            rcon.DSSaveGame()
+            rcon.DSServerShutdown()
+        except ConsoleError as e:
+            LOGGER.error(f"Could not shut down the Dedicated Server: {e.describe()}")
+            return False
+        finally:
+            rcon.disconnect()
+        
+        start_time = time.time()
+        
+        # The console port is closed as soon as the Dedicated Server has exited
+        while True:
+            time.sleep(RESTORE_SHUTDOWN_POLL_INTERVAL)
+            
+            rcon = self.connect_local_console()
+            
+            if rcon is None:
+                LOGGER.info("Dedicated Server has shut down")
+                return True
+            
+            rcon.disconnect()
+            
+            if (self.config.ShutdownGraceTimeout > 0) and ((time.time() - start_time) > self.config.ShutdownGraceTimeout):
+                LOGGER.error(f"The Dedicated Server didn't shut down within {self.config.ShutdownGraceTimeout} seconds")
+                return False
+    
+    def restore_backup(self, name, confirm=True):
+        """
+            Restores a backup over the 'Saved' directory of the Dedicated Server after stopping it, if it is running.
+            The current state is backed up before, so it isn't lost
+            
+            Arguments:
+                - name: File name or path of the backup
+                - [confirm]: Wether to ask the user for confirmation before restoring
+            
+            Returns: A boolean indicating the success
+        """
+        
+        backup_file = name
+        
+        # Also allow only the name of a file in the backup directory, optionally without extension
+        if not path.isfile(backup_file):
+            backup_file = path.join(self.config.backup.BackupPath, name if name.endswith(".zip") else f"{name}.zip")
+        
+        if not path.isfile(backup_file):
+            LOGGER.error(f"Backup '{name}' not found. Use the 'backup' command to list the available backups")
+            return False
+        
+        problem = backup.check_backup(backup_file)
+        
+        if problem is not None:
+            LOGGER.error(f"Backup '{backup_file}' can't be restored: {problem}")
+            return False
+        
+        if confirm:
+            if not sys.stdin.isatty():
+                LOGGER.error("Restoring a backup has to be confirmed, use --yes when not running interactively")
+                return False
+            
+            answer = input(f"Restoring '{path.basename(backup_file)}' stops the Dedicated Server, if it is running, and overwrites its savegames{' and configuration' if self.config.backup.IncludeConfig else ''}. Continue? [y/N] ")
+            
+            if answer.strip().lower() not in ["y", "yes"]:
+                LOGGER.info("Not restoring backup")
+                return False
+        
+        if not self.stop_local_server():
+            LOGGER.error("Not restoring backup, as the Dedicated Server is still running")
+            return False
+        
+        # Keep the current state, in case the wrong backup was chosen
+        if path.isdir(path.join(self.config.AstroServerPath, backup.ASTRO_SAVED_PATH)) and (self.create_backup("restore") is False):
+            LOGGER.error("Not restoring backup, as the current state could not be backed up")
+            return False
+        
+        try:
+            backup.restore_backup(backup_file, self.config.AstroServerPath)
+        except (OSError, zipfile.BadZipFile) as e:
+            LOGGER.error(f"Error while restoring backup: {str(e)}")
+            return False
+        
+        LOGGER.info(f"Restored backup '{path.basename(backup_file)}'")
+        
+        # The configuration of the Dedicated Server might have been restored too, so it has to be read again
+        if self.dedicatedserver:
+            self.dedicatedserver = AstroDedicatedServer(self)
+        
+        return True
     
-    def check_server_update(self, force_update=False):
+    def check_server_update(self, force_update=False, dry_run=False):
         """
             Checks if an update for the Astroneer Dedicated Server is available or if it needs to be installed.
-            Also performs update if set in config or {force_update} is set to True
+            Also performs update if set in config or {force_update} is set to True, unless {dry_run} is set to True
+            
+            Returns: Wether an update/installation is needed or None, if the newest version couldn't be determined or the update/installation failed
         """
         
         oldversion = read_build_version(self.config.AstroServerPath)
@@ -407,11 +2198,19 @@ class AstroTuxLauncher():
         do_update = False
         installed = True
         
+        integrity_problems = self.check_ds_integrity()
+        
         if (oldversion is None) or not self.check_ds_executable():
             # No version is present yet or executable not present, we need an update/installation
             LOGGER.warning("Astroneer Dedicated Server is not installed yet")
             do_update = True
             installed = False
+        elif len(integrity_problems) > 0:
+            # Files of the installation are missing or damaged, so it has to be installed again
+            LOGGER.warning(f"The Astroneer Dedicated Server installation is incomplete or damaged: {', '.join(integrity_problems)}")
+            self.notifications.send_event(interface.EventType.INSTALL_INCOMPLETE, problems=", ".join(integrity_problems), server_version=oldversion)
+            do_update = True
+            installed = False
         else:
             # Get current server version from Spycibot endpoint
             try:
@@ -423,6 +2222,7 @@ class AstroTuxLauncher():
                     do_update = True
             except Exception as e:
                 LOGGER.error(f"Error occured while checking for newest version: {str(e)}")
+                return None
 
         if do_update:
             if self.config.AutoUpdateServer:
@@ -431,32 +2231,82 @@ class AstroTuxLauncher():
                 else:
                     LOGGER.info("Automatically installing Astroneer Dedicated Server...")
             
-            if self.config.AutoUpdateServer or force_update:
-                self.update_server()
+            if dry_run:
+                LOGGER.info("Not installing/updating during dry run")
+            elif self.config.AutoUpdateServer or force_update:
+                if not self.update_server():
+                    return None
             else:
                 LOGGER.info("Not installing/updating automatically")
         else:
-            if force_update:
+            if force_update and not dry_run:
                 LOGGER.info("Noting to do")
             else:
                 LOGGER.info("No update available, the Astroneer Dedicated Server is on the newest version")
         
-    def start_server(self):
+        return do_update
+    
+    def send_heartbeat(self):
+        """
+            Sends a heartbeat to the configured URL and as an event, if the Dedicated Server is running and registered.
+            No heartbeat is sent otherwise, such that monitoring services notice, that the server is down
+        """
+        
+        ds = self.dedicatedserver
+        
+        if (ds is None) or (ds.status != ServerStatus.RUNNING) or not ds.registered:
+            LOGGER.debug("Not sending heartbeat, as the Dedicated Server is not running")
+            return
+        
+        if self.config.heartbeat.PingURL is not None:
+            try:
+                get_request(self.config.heartbeat.PingURL, timeout=self.config.notifications.Timeout)
+            except Exception as e:
+                LOGGER.warning(f"Could not send heartbeat to the ping URL: {str(e)}")
+        
+        player_count = len([pi for pi in ds.curr_player_list.playerInfo if pi.inGame]) if ds.curr_player_list is not None else 0
+        self.notifications.send_event(interface.EventType.HEARTBEAT, player_count=player_count, server_version=ds.build_version)
+    
+    def start_server(self, dry_run=False, update_first=False, whitelist_only=False):
         """
             Starts the Astroneer Dedicated Server after setting up environment
+            
+            Arguments:
+                - [dry_run]: Wether to only perform the checks and show the command line of the server instead of starting it
+                - [update_first]: Wether to install/update the DS before starting it regardless of the config and to not start it, if that fails
+                - [whitelist_only]: Wether to start the DS in maintenance mode, which enables the whitelist after the start regardless of its saved setting
         """
         
         # Check for and install DS update if wanted
-        self.check_server_update()
+        update_needed = self.check_server_update(force_update=update_first, dry_run=dry_run)
+        
+        if update_first and (update_needed is None):
+            LOGGER.error("Could not make sure, that the newest version of the Astroneer Dedicated Server is installed")
+            self.exit(reason="Update before start failed")
+        
+        if dry_run and not self.check_ds_executable():
+            LOGGER.error("The Astroneer Dedicated Server is not installed, so it couldn't be started")
         
         # If Playfab API can't be reached, we can't continue
         if not playfab.check_api_health():
             LOGGER.error("Playfab API is unavailable. Are you connected to the internet?")
             self.exit(reason="Playfab API unavailable")
         
-        # Make sure wine prefix is ready
-        if not self.update_wine_prefix():
-            self.exit(reason="Error while updating WINE prefix")
+        # Make sure wine prefix is ready, if the Dedicated Server is run using WINE
+        if self.config.UseWine:
+            if dry_run:
+                LOGGER.info("Not updating WINE prefix during dry run")
+            elif not self.update_wine_prefix():
+                self.exit(reason="Error while updating WINE prefix")
+            
+            if dry_run and not self.check_wine_prefix():
+                LOGGER.warning(f"The WINE prefix at '{self.config.WinePrefixPath}' doesn't exist or has not been initialized yet")
+            elif not self.check_wine_prefix():
+                LOGGER.error(f"The WINE prefix at '{self.config.WinePrefixPath}' doesn't exist or has not been initialized")
+                LOGGER.error("Make sure that the 'WinePrefixPath' config option points to a directory, that can be written to")
+                self.exit(reason="WINE prefix not available")
+        else:
+            LOGGER.debug("Not using WINE, skipping WINE prefix setup")
         
         # Check that ports are available for the Server and RCON
         if not self.dedicatedserver.check_ports_free():
@@ -466,9 +2316,24 @@ class AstroTuxLauncher():
         if self.config.CheckNetwork:
             self.check_network_config()
         
+        if dry_run:
+            cmd, env = self.dedicatedserver.get_command()
+            
+            LOGGER.info(f"The Dedicated Server would be started using the following command in '{self.config.AstroServerPath}':")
+            
+            if self.config.UseWine:
+                LOGGER.info(f"    WINEPREFIX={shlex.quote(env['WINEPREFIX'])} {shlex.join(cmd)}")
+            else:
+                LOGGER.info(f"    {shlex.join(cmd)}")
+            
+            LOGGER.info("Dry run finished, not starting the Dedicated Server")
+            return
+        
         LOGGER.debug("Starting input thread...")
         self.input_thread.start()
         
+        self.dedicatedserver.maintenance_mode = whitelist_only
+        
         # Prepare and start dedicated server
         try:
             if not self.dedicatedserver.start():
@@ -486,15 +2351,81 @@ class AstroTuxLauncher():
             LOGGER.info("Sending of status updated is enabled")
             self.status_thread.start()
         
+        # Check in the background, as the server output has to be processed in the meantime
+        if self.config.CheckNetwork and (self.config.NetworkCheckTime == NetworkCheckTiming.AFTER_REGISTRATION):
+            threading.Thread(target=self.check_server_reachable, daemon=True).start()
+        
+        if self.config.heartbeat.Interval > 0:
+            LOGGER.info(f"Sending heartbeats every {self.config.heartbeat.Interval} minutes")
+            self.heartbeat_thread = interface.HeartbeatThread(self.config.heartbeat.Interval * 60, self.send_heartbeat)
+            self.heartbeat_thread.start()
+        
         # Run Server Loop
         LOGGER.debug("Starting server loop...")
         self.dedicatedserver.server_loop()
     
     
+    def connect_server(self, host, port, password=None):
+        """
+            Connects to the console of an already running Astroneer Dedicated Server and runs the console loop until disconnected
+            
+            Arguments:
+                - host: The host the Dedicated Server is running on
+                - port: The console port of the Dedicated Server
+                - [password]: The console password of the Dedicated Server
+        """
+        
+        self.remoteserver = RemoteDedicatedServer(self, host, port, password)
+        
+        if not self.remoteserver.connect():
+            self.exit(reason="Could not connect to Dedicated Server")
+        
+        LOGGER.debug("Starting input thread...")
+        self.input_thread.start()
+        
+        LOGGER.info("Enter 'help' to get help about command usage and 'disconnect' to quit")
+        
+        # Run console loop
+        LOGGER.debug("Starting console loop...")
+        self.remoteserver.console_loop()
+    
     def user_exit(self, signal, frame):
         """ Callback for when user requests to exit the application """
         self.exit(graceful=True, reason="Received SIGINT signal")
     
+    def user_reload(self, signal, frame):
+        """ Callback for when user requests to reload the configuration file """
+        self.reload_config()
+    
+    def user_save(self, signal, frame):
+        """ Callback for when user requests to save the game (SIGUSR1). The game is saved by the server loop """
+        
+        if self.dedicatedserver is None:
+            LOGGER.warning("Received SIGUSR1 signal, but no Dedicated Server is managed by this launcher")
+            return
+        
+        self.save_requested = True
+    
+    def user_roll_logs(self, signal, frame):
+        """ Callback for when user requests to continue logging to new log files (SIGUSR2). The log files are rolled by the server loop """
+        self.log_roll_requested = True
+    
+    def roll_logs(self):
+        """ Continues logging to new log files and compresses the old ones, if configured """
+        
+        if not interface.LauncherLogging.roll_logfile():
+            LOGGER.warning("Not rolling log files, as the launcher doesn't log to a file")
+            return
+        
+        if self.dedicatedserver and (self.dedicatedserver.server_log is not None):
+            self.dedicatedserver.close_server_log()
+            self.dedicatedserver.open_server_log()
+        
+        if self.config.KeepUncompressedLogs is not None:
+            interface.LauncherLogging.compress_old_logs(self.config.LogPath, self.config.KeepUncompressedLogs)
+        
+        LOGGER.info(f"Continuing logging in new log file: {interface.LauncherLogging.logfile_path}")
+    
     def exit(self, graceful=False, reason=None):
         if graceful:
             if reason:
@@ -513,6 +2444,14 @@ class AstroTuxLauncher():
                 self.dedicatedserver.shutdown()
                 return
             else:
+                # Close connection to remote server, if connected
+                if self.remoteserver:
+                    self.remoteserver.disconnect()
+                
+                # The Dedicated Server has shut down, so its output is complete
+                if self.dedicatedserver:
+                    self.dedicatedserver.close_server_log()
+                
                 # If no server is running, exit directly
                 LOGGER.info("Goodbye!")
                 LOGGER.debug("Quitting with exit code 0...")
@@ -547,28 +2486,96 @@ if __name__ == "__main__":
     # Parse command line arguments
     parser = argparse.ArgumentParser()
     parser.add_argument("command", type=LauncherCommand, action=interface.EnumStoreAction, help=HELP_COMMAND)
-    parser.add_argument("-c", "--config_path", help="The location of the configuration file (default: %(default)s)", type=str, dest="config_path", default="launcher.toml")
+    parser.add_argument("-c", "--config_path", help="The location of the configuration file. If not given, the standard locations are searched and if no config file is found, it is created in the working directory", type=str, dest="config_path", default=None)
+    parser.add_argument("--profile", help="The name of the profile in the config file to use, e.g. for managing multiple servers using one config file", type=str, dest="profile", default=None)
+    parser.add_argument("--working_dir", help="The directory to change to at start, which relative paths are resolved against (Overrules config option)", type=str, dest="working_dir", default=None)
     parser.add_argument("-p", "--astro_path", help="The path of the Astroneer Dedicated Server installation (default: %(default)s)", dest="astro_path", default=None)
-    parser.add_argument("-d", "--depotdl_exec", help="The path to anm existing depotdownloader executable (default: %(default)s)", dest="depotdl_exec", default=None)
+    parser.add_argument("-d", "--depotdl_exec", help="The path to an existing depotdownloader executable (Overrules config option)", dest="depotdl_exec", default=None)
     parser.add_argument("-l", "--log_debug", help="Also log debug messages (Overrules config option)", action='store_true', dest="log_debug", default=False)
+    parser.add_argument("--no_file_log", help="Only log to the console and don't write log files, e.g. if the output is captured already (Overrules config option)", action="store_true", dest="no_file_log", default=False)
+    parser.add_argument("--log_format", help="How log messages are formatted on the console (Overrules config option)", type=interface.LogFormat, action=interface.EnumStoreAction, dest="log_format", default=None)
+    parser.add_argument("--no_color", help="Disable colorized console output (Also disabled, if the NO_COLOR environment variable is set)", action="store_true", dest="no_color", default=False)
+    parser.add_argument("-v", "--verbose", help="Show more log messages on the console. Can be given twice to also write debug messages to the log file", action="count", dest="verbose", default=0)
+    parser.add_argument("-q", "--quiet", help="Only show errors on the console", action="store_true", dest="quiet", default=False)
+    parser.add_argument("-w", "--wine_prefix", help="The path of the WINE prefix to use for running the Dedicated Server (Overrules config option)", type=str, dest="wine_prefix", default=None)
+    parser.add_argument("--wine_path", help="The path to the wine executable to use (Overrules config option)", type=str, dest="wine_path", default=None)
+    parser.add_argument("--server_port", help="The port of the Dedicated Server (Overrules config option)", type=int, dest="server_port", default=None)
+    parser.add_argument("--console_port", help="The console port of the Dedicated Server (Overrules config option)", type=int, dest="console_port", default=None)
+    parser.add_argument("--from_path", help="The config file to import settings from, used with 'migrate'", type=str, dest="from_path", default=None)
+    parser.add_argument("--host", help="The host of the Dedicated Server to connect to, used with 'connect' and 'watch' (default: %(default)s)", type=str, dest="host", default="127.0.0.1")
+    parser.add_argument("--port", help="The console port of the Dedicated Server to connect to, used with 'connect' and 'watch' (default: %(default)s)", type=int, dest="port", default=1234)
+    parser.add_argument("--dry_run", help="Only perform the checks and show how the Dedicated Server would be started, used with 'start'. Only check for an available update without installing it, used with 'update'", action="store_true", dest="dry_run", default=False)
+    parser.add_argument("--update_first", help="Install/update the Dedicated Server before starting it, even if AutoUpdateServer is disabled, and don't start it, if that fails, used with 'start'", action="store_true", dest="update_first", default=False)
+    parser.add_argument("--whitelist_only", help="Enable the whitelist after starting the Dedicated Server regardless of its saved setting, such that only whitelisted players can join, used with 'start'", action="store_true", dest="whitelist_only", default=False)
+    parser.add_argument("-n", "--lines", help="The number of lines to show, used with 'logs' (default: %(default)s)", type=int, dest="lines", default=50)
+    parser.add_argument("-f", "--follow", help="Keep showing new lines of the log file, used with 'logs'", action="store_true", dest="follow", default=False)
+    parser.add_argument("--log_file", help="Name or path of the log file to show instead of the newest one, used with 'logs'", type=str, dest="log_file", default=None)
+    parser.add_argument("--json", help="Output the information as JSON, used with 'status' and 'config'", action="store_true", dest="json", default=False)
+    parser.add_argument("--full", help="Also show the versions of the other components, used with 'version'", action="store_true", dest="full", default=False)
+    parser.add_argument("--create", help="Create a backup right away, saving the game first if the Dedicated Server is running, used with 'backup'", action="store_true", dest="create", default=False)
+    parser.add_argument("--restore", help="The name of the backup to restore, used with 'backup'", type=str, dest="restore", default=None)
+    parser.add_argument("--start", help="Start the Dedicated Server after restoring the backup, used with 'backup --restore'", action="store_true", dest="start", default=False)
+    parser.add_argument("-y", "--yes", help="Don't ask for confirmation before restoring a backup, used with 'backup --restore'", action="store_true", dest="yes", default=False)
+    parser.add_argument("--console_password", help="The console password of the Dedicated Server to connect to, used with 'connect' and 'watch' (default: %(default)s)", type=str, dest="console_password", default=None)
     
     args = parser.parse_args()
     
+    if not (1 <= args.port <= 65535):
+        parser.error(f"argument --port: {args.port} is not a valid port number")
+    
+    if (args.command == LauncherCommand.MIGRATE) and (args.from_path is None):
+        parser.error("the 'migrate' command requires the argument --from_path")
+    
+    # An explicitly given config file has to exist, except when migrating a config into it
+    if (args.config_path is not None) and (args.command != LauncherCommand.MIGRATE) and not path.isfile(args.config_path):
+        parser.error(f"the config file '{args.config_path}' doesn't exist")
+    
+    if args.quiet and (args.verbose > 0):
+        parser.error("the arguments -q/--quiet and -v/--verbose can't be used together")
+    
+    if args.create and (args.restore is not None):
+        parser.error("the arguments --create and --restore can't be used together")
+    
+    if args.start and (args.restore is None):
+        parser.error("the argument --start can only be used with --restore")
+    
+    # The report is created in the directory the launcher was started in, even if WorkingDirectory is set
+    report_dir = os.getcwd()
+    
+    # Only the JSON should be printed to stdout, so it can be parsed
+    json_output = args.json and (args.command in [LauncherCommand.STATUS, LauncherCommand.CONFIG])
+    
+    # The health check should be fast and only output its result
+    minimal_output = json_output or (args.command in [LauncherCommand.HEALTH, LauncherCommand.VERSION, LauncherCommand.WATCH, LauncherCommand.CONFIG])
     
-    # Set terminal window title
-    if CONTROL_CODES_SUPPORTED is None:
-        set_window_title(f"{NAME} - Unofficial Astroneer Dedicated Server Launcher for Linux")
+    # Adjust console log level and colors before any logging happens
+    if args.no_color:
+        interface.ConsoleOutput.set_color(False)
     
-    # Print Banner
-    print(BANNER_LOGO, end="")
-    print(BANNER_SUBTITLE)
-    print("")
-    print(BANNER_TEXT)
-    print(f"v{LAUNCHER_VERSION}")
-    print("")
+    interface.LauncherLogging.set_verbosity(-1 if (args.quiet or minimal_output) else args.verbose)
+    
+    # The format given on the command line overrules the one in the config file
+    console_log_format = args.log_format
+    
+    if console_log_format is None:
+        console_log_format = LauncherConfig.read_console_log_format(args.config_path if args.config_path is not None else LauncherConfig.find_config_file(), args.profile)
+    
+    # The banner would break up the JSON lines
+    if not minimal_output and (console_log_format != interface.LogFormat.JSON):
+        # Set terminal window title
+        if CONTROL_CODES_SUPPORTED is None:
+            set_window_title(f"{NAME} - Unofficial Astroneer Dedicated Server Launcher for Linux")
+        
+        # Print Banner, without the colors of the logo, if colors are disabled
+        print(BANNER_LOGO if interface.ConsoleOutput.color else interface.ANSI_ESCAPE_REGEX.sub("", BANNER_LOGO), end="")
+        print(BANNER_SUBTITLE)
+        print("")
+        print(BANNER_TEXT)
+        print(f"v{LAUNCHER_VERSION}")
+        print("")
     
     try:
-        launcher = AstroTuxLauncher(args.config_path, args.astro_path, args.depotdl_exec, force_debug_log=args.log_debug)
+        launcher = AstroTuxLauncher(args.config_path, args.astro_path, args.depotdl_exec, force_debug_log=args.log_debug, remote=(args.command == LauncherCommand.CONNECT), check_only=(args.command in [LauncherCommand.CHECK_CONFIG, LauncherCommand.MIGRATE, LauncherCommand.STATUS, LauncherCommand.LOGS, LauncherCommand.HEALTH, LauncherCommand.VERSION, LauncherCommand.WATCH, LauncherCommand.CONFIG, LauncherCommand.REPORT]) or ((args.command == LauncherCommand.BACKUP) and not args.start), server_port=args.server_port, console_port=args.console_port, wine_prefix=args.wine_prefix, wine_path=args.wine_path, log_to_file=(args.command not in [LauncherCommand.LOGS, LauncherCommand.HEALTH, LauncherCommand.VERSION, LauncherCommand.WATCH, LauncherCommand.CONFIG, LauncherCommand.REPORT]), working_dir=args.working_dir, no_file_log=args.no_file_log, profile=args.profile, log_format=args.log_format)
     except KeyboardInterrupt:
         print("Quitting... (requested by user)")
         sys.exit(0)
@@ -579,6 +2586,9 @@ if __name__ == "__main__":
         sys.exit(1)
     
     signal.signal(signal.SIGINT, launcher.user_exit)
+    signal.signal(signal.SIGHUP, launcher.user_reload)
+    signal.signal(signal.SIGUSR1, launcher.user_save)
+    signal.signal(signal.SIGUSR2, launcher.user_roll_logs)
     
     if CONTROL_CODES_SUPPORTED == False:
         LOGGER.debug("ANSI escape codes except color codes are disabled")
@@ -588,7 +2598,8 @@ if __name__ == "__main__":
     if args.command == LauncherCommand.INSTALL:
         LOGGER.info("Installing Astroneer Dedicated Server...")
         try:
-            launcher.update_server()
+            if not launcher.update_server():
+                sys.exit(1)
         except Exception as e:
             LOGGER.critical(f"Error while installing server on line {sys.exc_info()[-1].tb_lineno}: {type(e).__name__}: {e}")
             LOGGER.error(traceback.format_exc())
@@ -597,18 +2608,72 @@ if __name__ == "__main__":
         LOGGER.info("Checking for available updates to the Astroneer Dedicated Server...")
         
         try:
-            launcher.check_server_update(force_update=True)
+            update_available = launcher.check_server_update(force_update=True, dry_run=args.dry_run)
+            
+            if update_available is None:
+                sys.exit(1)
+            elif args.dry_run and update_available:
+                sys.exit(UPDATE_AVAILABLE_EXIT_CODE)
         except Exception as e:
             LOGGER.critical(f"Error while updating server on line {sys.exc_info()[-1].tb_lineno}: {type(e).__name__}: {e}")
             LOGGER.error(traceback.format_exc())
             sys.exit(1)
     elif args.command == LauncherCommand.START:
         try:
-            launcher.start_server()
+            launcher.start_server(dry_run=args.dry_run, update_first=args.update_first, whitelist_only=args.whitelist_only)
         except Exception as e:
             if launcher.dedicatedserver:
                 launcher.dedicatedserver.kill()
             
             raise
+    elif args.command == LauncherCommand.CHECK_CONFIG:
+        if not launcher.check_config():
+            sys.exit(1)
+    elif args.command == LauncherCommand.MIGRATE:
+        if not launcher.migrate_config(args.from_path):
+            sys.exit(1)
+    elif args.command == LauncherCommand.STATUS:
+        launcher.show_status(as_json=json_output)
+    elif args.command == LauncherCommand.WATCH:
+        if not launcher.watch_players(args.host, args.port, args.console_password):
+            sys.exit(1)
+    elif args.command == LauncherCommand.VERSION:
+        launcher.show_version(full=args.full)
+    elif args.command == LauncherCommand.CONFIG:
+        launcher.show_config(as_json=json_output)
+    elif args.command == LauncherCommand.BACKUP:
+        if args.restore is not None:
+            if not launcher.restore_backup(args.restore, confirm=not args.yes):
+                sys.exit(1)
+            
+            if args.start:
+                try:
+                    launcher.start_server()
+                except Exception as e:
+                    if launcher.dedicatedserver:
+                        launcher.dedicatedserver.kill()
+                    
+                    raise
+        elif args.create:
+            if not launcher.create_manual_backup():
+                sys.exit(1)
+        else:
+            launcher.list_backups()
+    elif args.command == LauncherCommand.REPORT:
+        if not launcher.create_report(report_dir):
+            sys.exit(1)
+    elif args.command == LauncherCommand.HEALTH:
+        if not launcher.check_health():
+            sys.exit(1)
+    elif args.command == LauncherCommand.LOGS:
+        if not launcher.show_logs(lines=args.lines, follow=args.follow, log_file=args.log_file):
+            sys.exit(1)
+    elif args.command == LauncherCommand.CONNECT:
+        try:
+            launcher.connect_server(args.host, args.port, args.console_password)
+        except Exception as e:
+            LOGGER.critical(f"Error in remote console session on line {sys.exc_info()[-1].tb_lineno}: {type(e).__name__}: {e}")
+            LOGGER.error(traceback.format_exc())
+            sys.exit(1)
     
     LOGGER.info("Goodbye!")
\ No newline at end of file
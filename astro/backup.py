@@ -0,0 +1,177 @@
+import os
+from os import path
+import zipfile
+import shutil
+import logging
+from datetime import datetime
+
+LOGGER = logging.getLogger("Backup")
+
+# Path of the directory containing savegames and configuration relative to the Astroneer DS installation
+ASTRO_SAVED_PATH = "Astro/Saved"
+
+# Name of the directory in 'Saved' the current savegames are moved to while a backup is restored
+SAVEGAMES_ASIDE_DIR = "SaveGames.old"
+
+def create_backup(astro_path, backup_path, prefix="backup", include_config=True, savegames_only=False):
+    """
+        Archives the 'Saved' directory of the Astroneer Dedicated Server installation into a timestamped zip file
+        
+        Arguments:
+            - astro_path: The path of the Astroneer Dedicated Server installation
+            - backup_path: The directory to store the backup in
+            - [prefix]: The prefix of the backup file name
+            - [include_config]: Wether to include the configuration files of the Dedicated Server
+            - [savegames_only]: Wether to only include the 'SaveGames' directory
+        
+        Returns: The path of the created backup file or None if there was nothing to back up
+    """
+    
+    saved_path = path.join(astro_path, ASTRO_SAVED_PATH)
+    
+    if not path.isdir(saved_path):
+        return None
+    
+    os.makedirs(backup_path, exist_ok=True)
+    
+    datetime_string = datetime.today().strftime("%Y-%m-%d_%H-%M-%S")
+    backup_file = path.join(backup_path, f"{prefix}_{datetime_string}.zip")
+    
+    with zipfile.ZipFile(backup_file, "w", compression=zipfile.ZIP_DEFLATED) as zf:
+        for root, dirs, files in os.walk(saved_path):
+            rel_root = path.relpath(root, saved_path)
+            top_dir = rel_root.split(os.sep)[0]
+            
+            # Skip directories, that should not be included
+            if savegames_only and (top_dir != "SaveGames"):
+                continue
+            
+            if (not include_config) and (top_dir == "Config"):
+                continue
+            
+            # Log files are not needed to restore the server
+            if top_dir == "Logs":
+                continue
+            
+            for filename in files:
+                file_path = path.join(root, filename)
+                zf.write(file_path, path.join("Saved", path.relpath(file_path, saved_path)))
+    
+    LOGGER.debug(f"Created backup '{backup_file}'")
+    
+    return backup_file
+
+def list_backups(backup_path):
+    """
+        Lists the backups stored in {backup_path}
+        
+        Arguments:
+            - backup_path: The directory the backups are stored in
+        
+        Returns: A list of (file name, modification time as datetime, size in bytes) tuples, newest first
+    """
+    
+    if not path.isdir(backup_path):
+        return []
+    
+    backups = []
+    
+    for filename in os.listdir(backup_path):
+        file_path = path.join(backup_path, filename)
+        
+        if filename.endswith(".zip") and path.isfile(file_path):
+            backups.append((filename, datetime.fromtimestamp(path.getmtime(file_path)), path.getsize(file_path)))
+    
+    return sorted(backups, key=lambda b: b[1], reverse=True)
+
+def check_backup(backup_file):
+    """
+        Checks, that {backup_file} is a backup, that can be restored, i.e. a valid zip file only containing files in the 'Saved' directory
+        
+        Returns: A message describing the problem or None, if the backup can be restored
+    """
+    
+    try:
+        with zipfile.ZipFile(backup_file, "r") as zf:
+            names = zf.namelist()
+            
+            if zf.testzip() is not None:
+                return "The archive is damaged"
+    except (OSError, zipfile.BadZipFile) as e:
+        return f"Not a valid zip file: {str(e)}"
+    
+    if len(names) == 0:
+        return "The archive is empty"
+    
+    for name in names:
+        # Also guard against paths leaving the 'Saved' directory
+        parts = name.replace("\\", "/").split("/")
+        
+        if (parts[0] != "Saved") or (".." in parts) or path.isabs(name):
+            return f"The archive contains '{name}', which is not part of the 'Saved' directory"
+    
+    return None
+
+def restore_backup(backup_file, astro_path):
+    """
+        Extracts a backup created using create_backup over the 'Saved' directory of the Astroneer Dedicated Server installation.
+        If the backup contains savegames, the current savegames are replaced by them. Other files, that are not part of the backup, are kept.
+        The backup should be checked using check_backup before
+        
+        Arguments:
+            - backup_file: The path of the backup file
+            - astro_path: The path of the Astroneer Dedicated Server installation
+    """
+    
+    saved_path = path.join(astro_path, ASTRO_SAVED_PATH)
+    savegames_path = path.join(saved_path, "SaveGames")
+    aside_path = path.join(saved_path, SAVEGAMES_ASIDE_DIR)
+    
+    with zipfile.ZipFile(backup_file, "r") as zf:
+        # Savegames newer than the ones in the backup would otherwise be kept and loaded by the Dedicated Server instead
+        has_savegames = any(name.replace("\\", "/").startswith("Saved/SaveGames/") for name in zf.namelist())
+        
+        if has_savegames and path.isdir(savegames_path):
+            if path.exists(aside_path):
+                shutil.rmtree(aside_path)
+            
+            os.rename(savegames_path, aside_path)
+        
+        try:
+            # The backup contains the 'Saved' directory itself, so it is extracted into its parent directory
+            zf.extractall(path.dirname(saved_path))
+        except Exception:
+            # Put the current savegames back, such that they aren't lost, if the backup couldn't be extracted
+            if path.isdir(aside_path):
+                shutil.rmtree(savegames_path, ignore_errors=True)
+                os.rename(aside_path, savegames_path)
+            
+            raise
+    
+    if path.isdir(aside_path):
+        shutil.rmtree(aside_path)
+    
+    LOGGER.debug(f"Restored backup '{backup_file}'")
+
+def remove_old_backups(backup_path, prefix="backup", max_backups=10):
+    """
+        Removes the oldest backups with the given {prefix}, such that at most {max_backups} remain
+        
+        Arguments:
+            - backup_path: The directory the backups are stored in
+            - [prefix]: The prefix of the backup file names
+            - [max_backups]: The number of backups to keep. If 0, no backups are removed
+    """
+    
+    if (max_backups <= 0) or not path.isdir(backup_path):
+        return
+    
+    # Timestamp in file name sorts chronologically
+    backups = sorted([f for f in os.listdir(backup_path) if f.startswith(f"{prefix}_") and f.endswith(".zip")])
+    
+    for filename in backups[:-max_backups]:
+        try:
+            os.remove(path.join(backup_path, filename))
+            LOGGER.debug(f"Removed old backup '{filename}'")
+        except OSError as e:
+            LOGGER.warning(f"Could not remove old backup '{filename}': {str(e)}")
@@ -8,18 +8,19 @@ from utils import net
 import logging
 from os import path
 import os
-from utils.misc import ExcludeIfNone, read_build_version, CONTROL_CODES_SUPPORTED
+from utils.misc import ExcludeIfNone, read_build_version, redact_secrets, normalize_name, format_duration, CONTROL_CODES_SUPPORTED, REDACTED
 from astro.rcon import PlayerCategory
 import re
 from typing import Optional, List
 import json
-from astro.rcon import AstroRCON, PlayerCategory
-from datetime import datetime
+from astro.rcon import AstroRCON, PlayerCategory, ConsoleTimeoutError, get_command_names
+from datetime import datetime, timezone
 import subprocess
 import pathvalidate
 import time
 import astro.playfab as playfab
-from utils.interface import EventType, ConsoleParser, ProcessOutputThread, AP_SPINNER
+from utils.interface import EventType, ConsoleParser, ConsoleOutput, LauncherLogging, ProcessOutputThread, AP_SPINNER
+from pansi import ansi
 import psutil
 from enum import Enum
 import socket
@@ -162,11 +163,13 @@ class DedicatedServerConfig:
     PlayerProperties: List[PlayerPropertiesEntry] = field(default_factory=list, metadata=pp_list_field)
     
     @staticmethod
-    def ensure_config(config_path, overwrite_ip=False):
+    def ensure_config(config_path, overwrite_ip=False, console_port=None, public_ip=None):
         """
             Reads the dedicated server configuration file at the given config_path, if present, baselines it using dataclass and exports it again.
             If the config file is not present yet, also creates it.
             Also ensures PublicIP setting is set correctly and overwrites it according to {overwrite_ip} and forces some settings to specific values.
+            If {overwrite_ip} and {public_ip} are set, the PublicIP setting is set to {public_ip} instead of the IP gotten from an online service.
+            If {console_port} is set, it overwrites the ConsolePort setting.
         """
         
         config = None
@@ -196,6 +199,9 @@ class DedicatedServerConfig:
             
             config = DedicatedServerConfig()
         
+        if console_port is not None:
+            config.ConsolePort = console_port
+        
         # Check Public IP field
         ip_valid = net.valid_ip(config.PublicIP)
         
@@ -203,8 +209,12 @@ class DedicatedServerConfig:
             ip_valid = False
             LOGGER.warning("PublicIP field in Dedicated Server config (AstroServerSettings.ini) contained a private IP")
         
+        # If requested, use the given IP, as the online service might not report the correct one
+        if overwrite_ip and (public_ip is not None):
+            LOGGER.info("Overwriting PublicIP field in Dedicated Server config with configured IP...")
+            config.PublicIP = public_ip
         # If requested or IP is invalid, replace with public IP gotten from online service
-        if overwrite_ip or not ip_valid:
+        elif overwrite_ip or not ip_valid:
             try:
                 LOGGER.info("Overwriting PublicIP field in Dedicated Server config...")
                 config.PublicIP = net.get_public_ip()
@@ -221,6 +231,49 @@ class DedicatedServerConfig:
         new_ini_config.write_file(config_path)
         
         return config
+    
+    @staticmethod
+    def read_config(config_path):
+        """
+            Reads the dedicated server configuration file at the given {config_path} without changing it
+            
+            Returns: The config or None, if the file doesn't exist
+        """
+        
+        if not path.isfile(config_path):
+            return None
+        
+        ini_dict = INIMultiConfig(filePath=config_path).get_dict()
+        
+        return DedicatedServerConfig.from_dict(ini_dict.get("/Script/Astro.AstroServerSettings", {}))
+    
+    @staticmethod
+    def update_config(config_path, **values):
+        """
+            Changes single settings in the dedicated server configuration file at the given {config_path}, leaving the other settings as they are
+            
+            Arguments:
+                - config_path: The path of the configuration file
+                - values: The names of the settings to change and their new values
+            
+            Returns: The updated config
+        """
+        
+        config = DedicatedServerConfig.read_config(config_path)
+        
+        if config is None:
+            raise ValueError("Dedicated Server configuration file doesn't exist")
+        
+        for key, value in values.items():
+            if not hasattr(config, key):
+                raise ValueError(f"Unknown Dedicated Server setting '{key}'")
+            
+            setattr(config, key, value)
+        
+        new_ini_config = INIMultiConfig(confDict={"/Script/Astro.AstroServerSettings": config.to_dict(encode_json=True)})
+        new_ini_config.write_file(config_path)
+        
+        return config
 
 
 @dataclass_json
@@ -280,10 +333,11 @@ class EngineConfig:
         return new_dict
     
     @staticmethod
-    def ensure_config(config_path, disable_encryption=True):
+    def ensure_config(config_path, disable_encryption=True, port=None):
         """
             Reads the engine configuration file at the given config_path, if present, baselines it using dataclass and exports it again.
             If the config file is not present yet, also creates it.
+            If {port} is set, it overwrites the port of the server.
         """
         
         config = None
@@ -298,9 +352,6 @@ class EngineConfig:
             
             config = EngineConfig()
             config.collect(ini_dict)
-            
-            # Overwrite some values to ensure specific values
-            config.AllowEncryption = not disable_encryption
 
         else:
             # If config file is not present, create directories and default config
@@ -308,7 +359,13 @@ class EngineConfig:
                 os.makedirs(path.dirname(config_path), exist_ok=True)
             
             config = EngineConfig()
-                
+        
+        # Overwrite some values to ensure specific values, also for newly created config files
+        config.AllowEncryption = not disable_encryption
+        
+        if port is not None:
+            config.Port = port
+        
         # Write config back to file to add missing entried and remove superflous ones
         # In the case of the file not existing prior, it will be created
         new_ini_config = INIMultiConfig(confDict=config.spread())
@@ -316,6 +373,22 @@ class EngineConfig:
         new_ini_config.write_file(config_path)
         
         return config
+    
+    @staticmethod
+    def read_config(config_path):
+        """
+            Reads the engine configuration file at the given {config_path} without changing it
+            
+            Returns: The config or None, if the file doesn't exist
+        """
+        
+        if not path.isfile(config_path):
+            return None
+        
+        config = EngineConfig()
+        config.collect(INIMultiConfig(filePath=config_path).get_dict())
+        
+        return config
 
 
 #
@@ -386,15 +459,101 @@ class ServerStatus(Enum):
 
 ASTRO_DS_CONFIG_PATH = "Astro/Saved/Config/WindowsServer/"
 
-class AstroDedicatedServer:
+# Known fatal problems in the output of the Dedicated Server with an ID identifying them and a message explaining what to do about them
+SERVER_ERROR_PATTERNS = [
+    ("port_in_use", re.compile(r"Address already in use|WSAEADDRINUSE|Failed to bind", re.IGNORECASE), "The server port is already in use. Stop the other process using it or change the port"),
+    ("playfab_auth", re.compile(r"PlayFab.*(Unauthorized|Login failed|failed to authenticate)", re.IGNORECASE), "Authentication with PlayFab failed. Check the network connection and that the system time is correct"),
+    ("savegame_load", re.compile(r"(Failed to load|Could not find|Unable to load).*save", re.IGNORECASE), "The active savegame could not be loaded. Check ActiveSaveFileDescriptiveName in AstroServerSettings.ini"),
+    ("wine_dll", re.compile(r"err:module:import_dll|Library \S+\.dll .*not found", re.IGNORECASE), "WINE could not load a required DLL. Try deleting the WINE prefix, so it is created again")
+]
+
+SERVER_ERROR_IDS = [error_id for error_id, _, _ in SERVER_ERROR_PATTERNS]
+
+# Time in seconds to wait for the server process to exit after terminating it, before killing it
+SHUTDOWN_TERMINATE_TIMEOUT = 10
+
+# Time in seconds to wait before retrying a failed restart after a crash, which is doubled after every failed attempt
+CRASH_RESTART_RETRY_DELAY = 5
+
+# Number of failed restarts after a crash in a row, after which the launcher waits for the user to intervene
+CRASH_RESTART_MAX_ATTEMPTS = 5
+
+# Remaining seconds of a shutdown/restart countdown at which players are warned
+SHUTDOWN_WARNING_TIMES = [600, 300, 120, 60, 30, 10, 5]
+
+# Directory of the log files written by the Dedicated Server relative to the Astroneer DS installation
+ASTRO_DS_LOG_PATH = "Astro/Saved/Logs"
+
+# Timestamp at the start of log lines written by the Dedicated Server, e.g. '[2023.06.01-10.11.12:123]'
+SERVER_LOG_TIMESTAMP_REGEX = re.compile(r"^\[(\d{4}\.\d{2}\.\d{2}-\d{2}\.\d{2}\.\d{2}):\d{3}\]")
+
+# Base filename of the files the raw output of the Dedicated Server is written to
+SERVER_LOG_BASE_FILENAME = "astroserver"
+
+# Range of the maximum player count, that the Dedicated Server accepts
+MIN_PLAYER_COUNT = 1
+MAX_PLAYER_COUNT = 8
+
+# Difference in seconds between the clocks of the launcher and the Dedicated Server above which a warning is logged
+CLOCK_SKEW_WARNING_THRESHOLD = 5
+
+def get_clock_skew(astro_path):
+    """
+        Estimates the difference between the clock used by the Dedicated Server and the clock of the system
+        by comparing the timestamp of the last line in the newest log file of the server with the time the file was last modified
+        
+        Arguments:
+            - astro_path: The path of the Astroneer Dedicated Server installation
+        
+        Returns: The difference in seconds (positive, if the clock of the server is ahead) or None, if it couldn't be determined
+    """
     
-    def __init__(self, launcher):
-        self.launcher = launcher
+    log_dir = path.join(astro_path, ASTRO_DS_LOG_PATH)
+    
+    try:
+        log_files = [path.join(log_dir, f) for f in os.listdir(log_dir) if f.endswith(".log")]
+        
+        if len(log_files) == 0:
+            return None
         
-        self.astro_path = self.launcher.config.AstroServerPath
-        self.wine_exec = self.launcher.wineexec
-        self.wineserver_exec = self.launcher.wineserverexec
-        self.wine_pfx = self.launcher.config.WinePrefixPath
+        log_file = max(log_files, key=path.getmtime)
+        modified_time = path.getmtime(log_file)
+        
+        # Only the end of the file is needed
+        with open(log_file, "rb") as lf:
+            lf.seek(max(path.getsize(log_file) - 16384, 0))
+            lines = lf.read().decode(errors="replace").splitlines()
+    except OSError as e:
+        LOGGER.debug(f"Could not read log files of the Dedicated Server: {str(e)}")
+        return None
+    
+    for line in reversed(lines):
+        match = SERVER_LOG_TIMESTAMP_REGEX.match(line)
+        
+        if match:
+            log_time = datetime.strptime(match.group(1), "%Y.%m.%d-%H.%M.%S")
+            
+            # The server may log using UTC or the local time, so use the interpretation closer to the system time
+            utc_skew = log_time.replace(tzinfo=timezone.utc).timestamp() - modified_time
+            local_skew = log_time.timestamp() - modified_time
+            
+            return round(min(utc_skew, local_skew, key=abs), 1)
+    
+    return None
+
+class DedicatedServerConsole:
+    """
+        Base class for interacting with an Astroneer Dedicated Server using its console port.
+        Contains the functionality, that is shared between a locally running and a remote Dedicated Server
+        
+        Arguments:
+            - launcher: The launcher object
+            - rcon: The AstroRCON object to use for communicating with the Dedicated Server
+    """
+    
+    def __init__(self, launcher, rcon):
+        self.launcher = launcher
+        self.rcon = rcon
         
         # Variables for storing data received from server
         self.curr_server_stat = None
@@ -404,358 +563,1226 @@ class AstroDedicatedServer:
         # Stores the time the last status update was performed
         self.last_server_status = None
         
-        # Load configuration
-        ds_config_path = path.join(self.astro_path, ASTRO_DS_CONFIG_PATH, "AstroServerSettings.ini")
-        engine_config_path = path.join(self.astro_path, ASTRO_DS_CONFIG_PATH, "Engine.ini")
-        
-        self.ds_config = DedicatedServerConfig.ensure_config(ds_config_path, self.launcher.config.OverwritePublicIP)
-        self.engine_config = EngineConfig.ensure_config(engine_config_path, self.launcher.config.DisableEncryption)
-        
-        LOGGER.debug(f"Dedicated Server configuration (including overrides):\n{json.dumps(self.ds_config.to_dict(encode_json=True), indent=4)}")
-        LOGGER.debug(f"Engine configuration (including overrides):\n{json.dumps(self.engine_config.to_dict(encode_json=True), indent=4)}")
-        
-        # Warning, if encryption is enables
-        if self.engine_config.AllowEncryption:
-            LOGGER.warning("Encryption is enabled. Currently, this doesn't work when running the Astroneer Dedicated Server using WINE")
-            LOGGER.warning("Players that have encryption disabled will also ne be able to play on a server having encryption enabled")
-        else:
-            LOGGER.info("NOTICE: Encryption is disabled. All players that want to join the Dedicated Server have to disable encryption on their clients too")
-        
-        # RCON
-        self.rcon = AstroRCON(self.ds_config.ConsolePort, self.ds_config.ConsolePassword)
-        
-        # DS Process related
-        self.process = None
-        self.process_out_queue = Queue()
-        self.process_out_thread = None
-        
-        # XAuth for playfab API
-        self.curr_xauth = None
-        self.time_last_xauth = None
-        
         # Status of the Dedicated Server
         self.status = ServerStatus.OFF
         self.build_version = None
         
-        # Information about Playfab registration
-        self.registered = False
-        self.lobby_id = None
-    
-    def reload_ds_config(self):
-        """ Reads the configuration file for the Dedicated Server again """
-        
-        ds_config_path = path.join(self.astro_path, ASTRO_DS_CONFIG_PATH, "AstroServerSettings.ini")
-        self.ds_config = DedicatedServerConfig.ensure_config(ds_config_path, self.launcher.config.OverwritePublicIP)
+        # Times, when the Dedicated Server was first registered in this session and registered after the last restart, and the number of restarts.
+        # Only known for a Dedicated Server started by this launcher
+        self.session_start_time = None
+        self.registered_time = None
+        self.restart_count = 0
     
-    def server_loop(self):
+    def execute_command(self, args):
         """
-            Loop to run while dedicated server is running that receives/sends data, executes commands and more
+            Executes a console command, that works the same for a local and a remote Dedicated Server
+            
+            Arguments:
+                - args: The parsed command arguments as returned by ConsoleParser.parse_input
+            
+            Returns: A boolean indicating, wether the command was handled
         """
         
-        while True:
-            # Exit loop, if server is off
-            if self.status == ServerStatus.OFF:
-                break
-            
-            # If RCON is not connected, try to connect
-            if not self.rcon.connected:
-                conn = self.rcon.ensureConnection()
+        if args["cmd"] == ConsoleParser.Command.INFO:
+            if (self.curr_server_stat is not None) and args["json"]:
+                info = self.curr_server_stat.to_dict(encode_json=True)
+                info["players"] = len([pi for pi in self.curr_player_list.playerInfo if pi.inGame]) if self.curr_player_list is not None else None
                 
-                # After connecting, toggle whiltelist quickly
-                if conn:
-                    self.quick_toggle_whitelist()
-                else:
-                    LOGGER.debug("Failed to connect RCON")
-            
-            # Check server process status
-            proc_status = self.process.poll()
-            if proc_status is not None:
-                if self.status == ServerStatus.STOPPING and proc_status == 0:
-                    LOGGER.info("Dedicated Server shut down gracefully")
-                    self.launcher.status_thread.update_status(status=False, message="Server shut down gracefully")
-                    break
+                if self.registered_time is not None:
+                    info["uptime"] = round(time.time() - self.registered_time)
+                    info["session_uptime"] = round(time.time() - self.session_start_time)
+                    info["restarts"] = self.restart_count
                 
-                if proc_status != 0:
-                    self.launcher.notifications.send_event(EventType.CRASH, server_version=self.build_version)
-                    self.launcher.status_thread.update_status(status=False, message="Server crashed")
+                self.print_json(info)
+            elif self.curr_server_stat is not None:
+                CMD_LOGGER.info("Information about the Dedicated Server:")
+                CMD_LOGGER.info(f"    - Build: {self.curr_server_stat.build}")
+                CMD_LOGGER.info(f"    - Server URL: {self.curr_server_stat.serverURL}")
+                CMD_LOGGER.info(f"    - Owner: {self.curr_server_stat.ownerName}")
+                CMD_LOGGER.info(f"    - Has Password: {'yes' if self.curr_server_stat.hasServerPassword else 'no'}")
+                CMD_LOGGER.info(f"    - Whitelist: {'enabled' if self.curr_server_stat.isEnforcingWhitelist else 'disabled'}")
+                CMD_LOGGER.info(f"    - Creative Mode: {'yes' if self.curr_server_stat.creativeMode else 'no'}")
+                CMD_LOGGER.info(f"    - Save Game: {self.curr_server_stat.saveGameName}")
                 
-                # Server process has exited
-                LOGGER.debug(f"Server process closed with exit code {proc_status}")
-                break
+                if self.curr_player_list is not None:
+                    CMD_LOGGER.info(f"    - Players: {len([pi for pi in self.curr_player_list.playerInfo if pi.inGame])}/{self.curr_server_stat.maxInGamePlayers}")
+                
+                CMD_LOGGER.info(f"    - Average FPS: {self.curr_server_stat.averageFPS}")
+                
+                if self.registered_time is not None:
+                    CMD_LOGGER.info(f"    - Uptime: {format_duration(time.time() - self.registered_time)} ({self.restart_count} restarts)")
             else:
-                # Print all lines currently in process output queue
-                while True:
-                    try:
-                        line = self.process_out_queue.get_nowait()
-                    except Empty:
-                        break
-                    else:
-                        line = line.replace("\n", "")   # Remove newline character, since it it unnecessary
-                        LOGGER.debug(f"[AstroDS] {line}")
-            
-            # If not connected to RCON, skip following code as it requires RCON
-            if not self.rcon.connected:
-                logging("RCON is not connected, skipping related functionality")
-                time.sleep(self.launcher.config.ServerStatusInterval)
-                continue
+                CMD_LOGGER.info("Server information not available right now")
+
+        elif args["cmd"] == ConsoleParser.Command.KICK:
+            self.kick_player(name=args["player"], guid=args["player"])
+
+        elif args["cmd"] == ConsoleParser.Command.KICK_ALL:
+            kicked, failed = self.kick_all_players(args["reason"])
             
-            update_server_data = False
+            if kicked is None:
+                CMD_LOGGER.warning("There was a problem while getting the list of online players")
+            elif failed > 0:
+                CMD_LOGGER.warning(f"Kicked {kicked} player(s), {failed} player(s) could not be kicked")
+            else:
+                CMD_LOGGER.info(f"Kicked {kicked} player(s)")
+
+        elif args["cmd"] == ConsoleParser.Command.WHITELIST:
+            if args["subcmd"] == ConsoleParser.WhitelistSubcommand.ENABLE:
+                success = self.set_whitelist_enabled(True)
+                
+                if success:
+                    CMD_LOGGER.info("Successfully enabled whitelist")
+            elif args["subcmd"] == ConsoleParser.WhitelistSubcommand.DISABLE:
+                success = self.set_whitelist_enabled(False)
+                
+                if success:
+                    CMD_LOGGER.info("Successfully disabled whitelist")
+            elif args["subcmd"] == ConsoleParser.WhitelistSubcommand.STATUS:
+                success = True
+                CMD_LOGGER.info(f"The whitelist is currently {'enabled' if self.curr_server_stat.isEnforcingWhitelist else 'disabled'}")
             
-            if self.last_server_status is None:
-                LOGGER.debug("Doing initial Server status data update")
+            if not success:
+                CMD_LOGGER.warning("There was a problem while setting the whitelist status")
+
+        elif args["cmd"] == ConsoleParser.Command.LIST:
+            if self.curr_player_list is not None:
+                if args["category"] == ConsoleParser.ListCategory.ALL:
+                    category = None
+                else:
+                    category = PlayerCategory[args["category"].name]
                 
-                # If we haven't requested any data yet, do it now
-                update_server_data = False
-                if self.update_server_info():
-                    self.last_server_status = time.time()
+                # Filter players based on online status and category
+                filtered_players = []
+                
+                for pi in self.curr_player_list.playerInfo:
+                    if (pi.inGame or args["include_offline"]) and ((category is None) or (category == pi.playerCategory)):
+                        filtered_players.append(pi)
+                
+                if args["json"]:
+                    self.print_json([self.player_info_dict(pi) for pi in filtered_players])
+                    return True
+                
+                # Output player info
+                list_name = "Known" if args["include_offline"] else "Online"
+                
+                if category is None:
+                    CMD_LOGGER.info(f"{list_name} Players ({len(filtered_players)}):")
                 else:
-                    LOGGER.warning("Getting information from Dedicated Server failed!")
+                    CMD_LOGGER.info(f"{list_name} '{category.value}' Players ({len(filtered_players)}):")
                 
-            elif (time.time() - self.last_server_status) >= self.launcher.config.ServerStatusInterval:
-                # If the time interval since the last status update is big wnough, do another one
-                update_server_data = True
+                if len(filtered_players) > 0:
+                    rows = []
+                    
+                    for pi in filtered_players:
+                        status = ConsoleOutput.colorize("online", ansi.GREEN) if pi.inGame else ConsoleOutput.colorize("offline", ansi.RED)
+                        rows.append([pi.playerName, pi.playerGuid, pi.playerCategory.value, status])
+                    
+                    for line in ConsoleOutput.format_table(["Name", "GUID", "Category", "Status"], rows):
+                        CMD_LOGGER.info(line)
+            else:
+                CMD_LOGGER.info("Player information not available right now")
+
+        elif args["cmd"] == ConsoleParser.Command.SAVEGAME:
+            if args["subcmd"] == ConsoleParser.SaveGameSubcommand.LOAD:
+                if (self.curr_game_list is not None) and (args["save_name"] == self.curr_game_list.activeSaveName):
+                    CMD_LOGGER.warning("Specified save is currently already loaded")
+                else:
+                    try:
+                        success = self.load_game(args["save_name"])
+                        
+                        if success:
+                            CMD_LOGGER.info(f"Loading save game '{args['save_name']}'...")
+                        else:
+                            CMD_LOGGER.warning("There was a problem while executing the command")
+                    except Exception as e:
+                        CMD_LOGGER.error(f"Error while executing command: {str(e)}")
+                        CMD_LOGGER.error(traceback.format_exc())
             
-            # Ensure XAuth is present
-            if update_server_data:
+            if args["subcmd"] == ConsoleParser.SaveGameSubcommand.SAVE:
                 try:
-                    self.get_XAuth()
+                    success = self.save_game(args["save_name"])
+                    
+                    if success:
+                        CMD_LOGGER.info("Saving the game...")
+                    else:
+                        CMD_LOGGER.warning("There was a problem while executing the command")
                 except Exception as e:
-                    LOGGER.error(str(e))
-            
-            #TODO: Get info from Playfab API
-            
-            #
-            # Update Server status data and compare to previous data
-            # to get joined and left players aswell as savegame changes
-            #
+                    CMD_LOGGER.error(f"Error while executing command: {str(e)}")
+                    CMD_LOGGER.error(traceback.format_exc())
             
-            if update_server_data and not (self.status == ServerStatus.STOPPING):                
+            if args["subcmd"] == ConsoleParser.SaveGameSubcommand.NEW:
                 try:
-                    prev_online_players = [pi for pi in self.curr_player_list.playerInfo if pi.inGame]
-                    prev_online_player_guids = [pi.playerGuid for pi in prev_online_players]
-                    
-                    prev_active_save_name = self.curr_game_list.activeSaveName
+                    success = self.new_game(args["save_name"])
                     
-                    if (prev_active_save_name is not None) and (prev_active_save_name != ""):
-                        save_date_list = [gi.date for gi in self.curr_game_list.gameList if gi.name == prev_active_save_name]
-                        prev_active_save_time = save_date_list[0] if len(save_date_list) > 0 else 0
+                    if success:
+                        CMD_LOGGER.info("Creating new save game...")
                     else:
-                        prev_active_save_time = 0
+                        CMD_LOGGER.warning("There was a problem while executing the command")
+                except Exception as e:
+                    CMD_LOGGER.error(f"Error while executing command: {str(e)}")
+                    CMD_LOGGER.error(traceback.format_exc())
+            
+            if args["subcmd"] == ConsoleParser.SaveGameSubcommand.LIST:
+                if (self.curr_game_list is not None) and args["json"]:
+                    self.print_json({
+                        "activeSaveName": self.curr_game_list.activeSaveName,
+                        "saves": [{
+                            "name": gi.name,
+                            "date": gi.date.isoformat() if isinstance(gi.date, datetime) else gi.date,
+                            "creative": gi.bHasBeenFlaggedAsCreativeModeSave,
+                            "active": gi.name == self.curr_game_list.activeSaveName
+                        } for gi in self.curr_game_list.gameList]
+                    })
+                elif self.curr_game_list is not None:
                     
-                    if self.update_server_info():
-                        self.last_server_status = time.time()
+                    CMD_LOGGER.info(f"Savegames ({len(self.curr_game_list.gameList)}):")
+                    
+                    if len(self.curr_game_list.gameList) > 0:
+                        rows = []
                         
-                        online_players = [pi for pi in self.curr_player_list.playerInfo if pi.inGame]
-                        online_player_guids = [pi.playerGuid for pi in online_players]
+                        for gi in self.curr_game_list.gameList:
+                            active = ConsoleOutput.colorize("active", ansi.GREEN) if gi.name == self.curr_game_list.activeSaveName else ""
+                            date = gi.date.strftime("%Y-%m-%d %H:%M:%S") if isinstance(gi.date, datetime) else gi.date
+                            rows.append([gi.name, date, "yes" if gi.bHasBeenFlaggedAsCreativeModeSave else "no", active])
                         
-                        # If the amount of players now is greater than before the update, players have joined
-                        if len(online_players) > len(prev_online_players):
-                            # Get difference of Player GUIDs to find out, who joined
-                            player_diff_guid = list(set(online_player_guids) - set(prev_online_player_guids))
-                            
-                            # Maybe redundant check
-                            if len(player_diff_guid) > 0:
-                                player_diff = [{"name": pi.playerName, "guid": pi.playerGuid} for pi in self.curr_player_list.playerInfo if pi.playerGuid in player_diff_guid]
-                                
-                                for info in player_diff:
-                                    self.launcher.notifications.send_event(EventType.PLAYER_JOIN, player_name=info["name"], player_guid=info["guid"], server_version=self.build_version)
-                                    
-                                    #TODO: Maybe set players to pending with command and refresh config file
+                        for line in ConsoleOutput.format_table(["Name", "Date", "Creative", "Status"], rows):
+                            CMD_LOGGER.info(line)
+                else:
+                    CMD_LOGGER.info("Savegame information not available right now")
 
-                        # If the amount of players now is smaller than before the update, players have left
-                        if len(prev_online_players) > len(online_players):
-                            # Get difference of Player GUIDs to find out, who left
-                            player_diff_guid = list(set(prev_online_player_guids) - set(online_player_guids))
-                            
-                            # Maybe redundant check
-                            if len(player_diff_guid) > 0:
-                                player_diff = [{"name": pi.playerName, "guid": pi.playerGuid} for pi in self.curr_player_list.playerInfo if pi.playerGuid in player_diff_guid]
-                                
-                                for info in player_diff:
-                                    self.launcher.notifications.send_event(EventType.PLAYER_LEAVE, player_name=info["name"], player_guid=info["guid"], server_version=self.build_version)
-                        
-                        # Get current savegame information
-                        active_save_name = self.curr_game_list.activeSaveName
-                        
-                        if (active_save_name is not None) and (active_save_name != ""):
-                            save_date_list = [gi.date for gi in self.curr_game_list.gameList if gi.name == active_save_name]
-                            active_save_time = save_date_list[0] if len(save_date_list) > 0 else 0
-                        else:
-                            active_save_time = 0
-                        
-                        # If active save names are different, the server changed savegame
-                        if active_save_name != prev_active_save_name:
-                            self.launcher.notifications.send_event(EventType.SAVEGAME_CHANGE, savegame_name=active_save_name, server_version=self.build_version)
-                        else:
-                            # If save was not changed, check if server saved the game
-                            if active_save_time != prev_active_save_time:
-                                self.launcher.notifications.send_event(EventType.SAVE, savegame_name=active_save_name, server_version=self.build_version)
-                    else:
-                        LOGGER.error("Error while getting data from dedicated server")
-                except Exception as e:
-                    LOGGER.debug(f"Error while doing status update: {str(e)}")
-                    LOGGER.error(traceback.format_exc())
-            
-            
-            # Handle console commands in queue
-            while not self.launcher.cmd_queue.empty():                
-                args = self.launcher.cmd_queue.get()
-                
-                
-                #TODO: Change functions in server such that they return a boolean AND a message, which makes logging easier
+        elif args["cmd"] == ConsoleParser.Command.PLAYER:
+            if args["subcmd"] == ConsoleParser.PlayerSubcommand.SET:
+                res = self.set_player_category(category=PlayerCategory[args["category"].name], name=args["player"], guid=args["player"])
                 
+                if res is None:
+                    CMD_LOGGER.warning("Specified player was not found")
+                elif res == True:
+                    CMD_LOGGER.info("Successfully changed player category")
+                else:
+                    CMD_LOGGER.info("There was a problem while executing the command")
+            
+            elif args["subcmd"] == ConsoleParser.PlayerSubcommand.GET:
+                player_info = self.get_player_info(name=args["player"], guid=args["player"])
                 
-                try:
-                    if args["cmd"] == ConsoleParser.Command.SHUTDOWN:
-                        success = self.shutdown()
+                if player_info and args["json"]:
+                    self.print_json(self.player_info_dict(player_info))
+                elif player_info:
+                    CMD_LOGGER.info("Player Information:")
+                    CMD_LOGGER.info(f"  - Name: {player_info.playerName}")
+                    CMD_LOGGER.info(f"  - GUID: {player_info.playerGuid}")
+                    CMD_LOGGER.info(f"  - Category: {player_info.playerCategory.value}")
+                    CMD_LOGGER.info(f"  - Online: {'yes' if player_info.inGame else 'no'}")
+                else:
+                    CMD_LOGGER.info("Player information not available right now")
+
+        elif args["cmd"] == ConsoleParser.Command.BAN:
+            res = self.ban_player(name=args["player"], guid=args["player"], reason=args["reason"])
+            
+            if res is None:
+                CMD_LOGGER.warning("Specified player was not found")
+            elif res == True:
+                CMD_LOGGER.info("Successfully banned player")
+            else:
+                CMD_LOGGER.warning("There was a problem while executing the command")
+
+        elif args["cmd"] == ConsoleParser.Command.UNBAN:
+            res = self.unban_player(name=args["player"], guid=args["player"])
+            
+            if res is None:
+                CMD_LOGGER.warning("Specified player was not found")
+            elif res == True:
+                CMD_LOGGER.info("Successfully unbanned player")
+            else:
+                CMD_LOGGER.warning("There was a problem while executing the command")
+
+        elif args["cmd"] == ConsoleParser.Command.BROADCAST:
+            try:
+                success = self.broadcast_message(" ".join(args["message"]))
+                
+                if success:
+                    CMD_LOGGER.info("Sent message to all players")
+                else:
+                    CMD_LOGGER.warning("There was a problem while executing the command")
+            except NotImplementedError as e:
+                CMD_LOGGER.error(f"Command not supported: {str(e)}")
+
+        elif args["cmd"] == ConsoleParser.Command.EVENTS:
+            events = self.launcher.event_history.get_events(args["count"])
+            
+            if len(events) > 0:
+                CMD_LOGGER.info(f"Recent events ({len(events)}):")
+                
+                rows = [[event_time.strftime("%Y-%m-%d %H:%M:%S"), event_type.value, message] for event_time, event_type, message in events]
+                
+                for line in ConsoleOutput.format_table(["Time", "Event", "Message"], rows):
+                    CMD_LOGGER.info(line)
+            else:
+                CMD_LOGGER.info("No events recorded yet")
+
+        elif args["cmd"] == ConsoleParser.Command.RAW:
+            command = " ".join(args["command"])
+            
+            try:
+                res = self.send_raw_command(command)
+                
+                if isinstance(res, (dict, list)):
+                    CMD_LOGGER.info(f"Response:\n{json.dumps(res, indent=4)}")
+                elif isinstance(res, bytes):
+                    CMD_LOGGER.info(f"Response: {res.decode(errors='replace')}")
+                elif self.rcon.last_error is not None:
+                    CMD_LOGGER.warning(f"There was a problem while executing the command: {self.rcon.last_error.describe()}")
+                else:
+                    CMD_LOGGER.warning("There was a problem while executing the command")
+            except ConsoleTimeoutError as e:
+                CMD_LOGGER.warning(f"No response to raw command: {str(e)}")
+        else:
+            return False
+        
+        return True
+    
+    @staticmethod
+    def print_json(data):
+        """ Prints {data} as JSON without any log formatting, such that it can be parsed """
+        
+        print(json.dumps(data, indent=4), flush=True)
+    
+    @staticmethod
+    def player_info_dict(player_info):
+        """ Returns the information about a player from the PlayerInfo object {player_info} as a dictionary for JSON output """
+        
+        return {
+            "name": player_info.playerName,
+            "guid": player_info.playerGuid,
+            "category": player_info.playerCategory.value,
+            "online": player_info.inGame
+        }
+    
+    # Server interaction methods (RCON)
+    
+    def get_player_info(self, name=None, guid=None):
+        """
+            Get the PlayerInfo object related to the player whose name or GUID match.
+            If no name matches exactly, the name is compared case-insensitively, as long as only one player matches
+        """
+        
+        if (name is None) and (guid is None):
+            raise ValueError("One of name, guid has to be provided")
+        
+        if not self.curr_player_list:
+            return None
+        
+        for player_info in self.curr_player_list.playerInfo:
+            if ((name and player_info.playerName == name)
+                or (guid and player_info.playerGuid == guid)):
+                return player_info
+        
+        if name:
+            matches = [pi for pi in self.curr_player_list.playerInfo if normalize_name(pi.playerName) == normalize_name(name)]
+            
+            if len(matches) == 1:
+                return matches[0]
+        
+        return None
+    
+    def set_player_category(self, category, name=None, guid=None, force=False):
+        """
+            Sets the category of the player identified by either the name or guid.
+            
+            Arguments:
+                - category: A rcon.PlayerCategory to set
+                - name/guid: Name/GUID to identify the Player
+                - force: Wether to send the command without checking the player list first.
+                    Only works, if {name} is set
+            
+            Returns: A boolean indicating the success or None if the player was not found
+        """
+        
+        if (name is None) and (guid is None):
+            raise ValueError("One of name, guid has to be provided")
+        
+        if not self.rcon.connected or (self.status != ServerStatus.RUNNING):
+            return False
+        
+        if force:
+            if name is None:
+                raise ValueError("force=True can only be used if a name is given")
+            
+            res = self.rcon.DSSetPlayerCategoryForPlayerName(name, category)
+        else:
+            player_info = self.get_player_info(name=name, guid=guid)
+            
+            if player_info is None:
+                return None
+            
+            res = self.rcon.DSSetPlayerCategoryForPlayerName(player_info.playerName, category)
+        
+        if not isinstance(res, dict):
+            return False
+        
+        return res["status"]
+    
+    def set_whitelist_enabled(self, enabled=True):
+        """
+            Changes the enables state of the Whitelist
+            
+            Arguments:
+                - enabled: Wether to enable/disable the whitelist
+            
+            Returns: A boolean indicating the success
+        """
+        
+        if not self.rcon.connected or (self.status != ServerStatus.RUNNING):
+            return False
+        
+        if self.curr_server_stat and (self.curr_server_stat.isEnforcingWhitelist == enabled):
+            return True
+        
+        res = self.rcon.DSSetDenyUnlisted(enabled)
+        
+        if not isinstance(res, bytes):
+            return False
+        
+        res = res.decode()
+        if res[:67] == "UAstroServerCommExecutor::DSSetDenyUnlisted: SetDenyUnlistedPlayers" and res[-1:] == "1":
+            self.curr_server_stat.isEnforcingWhitelist = enabled
+            return True
+        else:
+            return False
+    
+    def kick_player(self, guid=None, name=None, force=False):
+        """
+            Kicks the player identified by name/guid.
+            
+            Arguments:
+                - name/guid: Name/GUID to identify the Player
+                - force: Wether to send the command without checking the player list first.
+                    Only works, if {guid} is set
+            
+            Returns: A boolean indicating the success
+        """
+        
+        if (name is None) and (guid is None):
+            raise ValueError("One of name, guid has to be provided")
+        
+        if not self.rcon.connected or (self.status != ServerStatus.RUNNING):
+            return False
+        
+        if force:
+            if guid is None:
+                raise ValueError("force=True can only be used if a guid is given")
+            
+            res = self.rcon.DSKickPlayerGuid(guid)
+        else:
+            player_info = self.get_player_info(name=name, guid=guid)
+            
+            if player_info is None:
+                LOGGER.warning("Unknown Player")
+                return False
+            
+            res = self.rcon.DSKickPlayerGuid(player_info.playerGuid)
+        
+        if not isinstance(res, bytes):
+            LOGGER.warning("Error while executing command")
+            return False
+        
+        res = res.decode()
+        success = res[:42] == "UAstroServerCommExecutor::DSKickPlayerGuid" and res[-1:] == "d"
+        
+        if success:
+            if force:
+                LOGGER.info(f"Kicked Player with GUID '{guid}'")
+            else:
+                LOGGER.info(f"Kicked Player '{player_info.playerName}'")
+            
+            return True
+        else:
+            return success
+    
+    def get_online_players(self):
+        """
+            Requests the current player list from the server
+            
+            Returns: A list of the PlayerInfo of the players, that are in game right now, or None, if the list couldn't be received
+        """
+        
+        res = self.rcon.DSListPlayers()
+        
+        if not isinstance(res, dict):
+            return None
+        
+        try:
+            self.curr_player_list = PlayerList.from_dict(res)
+        except Exception as e:
+            LOGGER.error(f"Error while decoding received player list: {type(e)}: {str(e)}")
+            return None
+        
+        return [pi for pi in self.curr_player_list.playerInfo if pi.inGame]
+    
+    def kick_all_players(self, reason=None):
+        """
+            Kicks all players, that are in game right now.
+            Players, that left the game before they could be kicked, are not counted as failed
+            
+            Arguments:
+                - [reason]: The reason for kicking the players, which is included in the sent event
+            
+            Returns: The number of kicked players and the number of players, that could not be kicked, as a tuple. The number of kicked players is None, if the player list couldn't be received
+        """
+        
+        if not self.rcon.connected or (self.status != ServerStatus.RUNNING):
+            return None, 0
+        
+        online_players = self.get_online_players()
+        
+        if online_players is None:
+            return None, 0
+        
+        failed_guids = []
+        
+        for pi in online_players:
+            if not self.kick_player(guid=pi.playerGuid, force=True):
+                failed_guids.append(pi.playerGuid)
+        
+        # Players, that disconnected since the list was received, can't be kicked anymore, but are gone nevertheless
+        if len(failed_guids) > 0:
+            remaining_players = self.get_online_players()
+            
+            if remaining_players is not None:
+                remaining_guids = [pi.playerGuid for pi in remaining_players]
+                failed_guids = [guid for guid in failed_guids if guid in remaining_guids]
+        
+        kicked = len(online_players) - len(failed_guids)
+        
+        self.launcher.notifications.send_event(EventType.KICK_ALL, player_count=kicked, reason=reason if reason else "none given", server_version=self.build_version)
+        
+        return kicked, len(failed_guids)
+    
+    def ban_player(self, guid=None, name=None, reason=None):
+        """
+            Bans the player identified by name/guid by blacklisting them and kicking them, if they are online.
+            
+            Arguments:
+                - name/guid: Name/GUID to identify the Player
+                - [reason]: The reason for the ban, which is included in the sent event
+            
+            Returns: A boolean indicating the success or None if the player was not found
+        """
+        
+        if (name is None) and (guid is None):
+            raise ValueError("One of name, guid has to be provided")
+        
+        if not self.rcon.connected or (self.status != ServerStatus.RUNNING):
+            return False
+        
+        player_info = self.get_player_info(name=name, guid=guid)
+        
+        if player_info is None:
+            return None
+        
+        res = self.set_player_category(PlayerCategory.BLACKLISTED, name=player_info.playerName, force=True)
+        
+        if res != True:
+            return False
+        
+        player_info.playerCategory = PlayerCategory.BLACKLISTED
+        
+        # Blacklisting alone doesn't remove players, that are currently playing
+        if player_info.inGame:
+            if not self.kick_player(guid=player_info.playerGuid, force=True):
+                LOGGER.warning(f"Player '{player_info.playerName}' was blacklisted, but could not be kicked")
+        
+        self.launcher.notifications.send_event(EventType.PLAYER_BANNED, player_name=player_info.playerName, player_guid=player_info.playerGuid, reason=reason if reason else "None given", server_version=self.build_version)
+        
+        return True
+    
+    def unban_player(self, guid=None, name=None):
+        """
+            Unbans the player identified by name/guid by setting their category back to unlisted.
+            
+            Arguments:
+                - name/guid: Name/GUID to identify the Player
+            
+            Returns: A boolean indicating the success or None if the player was not found
+        """
+        
+        if (name is None) and (guid is None):
+            raise ValueError("One of name, guid has to be provided")
+        
+        player_info = self.get_player_info(name=name, guid=guid)
+        
+        if player_info is None:
+            return None
+        
+        if player_info.playerCategory != PlayerCategory.BLACKLISTED:
+            LOGGER.info(f"Player '{player_info.playerName}' is not banned")
+            return True
+        
+        res = self.set_player_category(PlayerCategory.UNLISTED, name=player_info.playerName, force=True)
+        
+        if res == True:
+            player_info.playerCategory = PlayerCategory.UNLISTED
+        
+        return res
+    
+    def broadcast_message(self, message):
+        """
+            Sends a message to all players on the server.
+            
+            WARNING: The console of the Dedicated Server doesn't offer a way to message players yet,
+                so this currently always raises a NotImplementedError
+            
+            Arguments:
+                - message: The message to send
+            
+            Returns: A boolean indicating the success
+        """
+        
+        if not self.rcon.connected or (self.status != ServerStatus.RUNNING):
+            return False
+        
+        raise NotImplementedError("The console of the Astroneer Dedicated Server doesn't support sending messages to players")
+    
+    def send_raw_command(self, command):
+        """
+            Sends a console command to the Dedicated Server without validating it
+            
+            Arguments:
+                - command: The command to send as-is
+            
+            Returns: The response of the Dedicated Server or None, if it couldn't be sent
+        """
+        
+        if not self.rcon.connected or (self.status != ServerStatus.RUNNING):
+            return None
+        
+        LOGGER.debug(f"Sending raw console command '{command}'. Raw commands are not validated and may have unexpected effects")
+        
+        return self.rcon.sendRaw(command)
+    
+    def update_server_info(self):
+        """
+            Updates the stored information about the dedicated server
+            
+            Returns: A boolean indicating the success
+        """
+        
+        if not self.rcon.connected or (self.status != ServerStatus.RUNNING):
+            return False
+        
+        try:
+            res = self.rcon.DSServerStatistics()
+            
+            if not isinstance(res, dict):
+                return False
+            
+            try:
+                self.curr_server_stat = ServerStatistics.from_dict(res)
+            except Exception as e:
+                LOGGER.error(f"Error while decoding received server statistics: {type(e)}: {str(e)}")
+                LOGGER.debug(f"Received response: {json.dumps(res)}")
+            
+            res = self.rcon.DSListPlayers()
+            
+            if not isinstance(res, dict):
+                return False
+            
+            try:
+                self.curr_player_list = PlayerList.from_dict(res)
+            except Exception as e:
+                LOGGER.error(f"Error while decoding received player list: {type(e)}: {str(e)}")
+                LOGGER.debug(f"Received response: {json.dumps(res)}")
+            
+            res = self.rcon.DSListGames()
+            
+            if not isinstance(res, dict):
+                return False
+            
+            try:
+                self.curr_game_list = GameList.from_dict(res)
+            except Exception as e:
+                LOGGER.error(f"Error while decoding received savegame list: {type(e)}: {str(e)}")
+                LOGGER.debug(f"Received response: {json.dumps(res)}")
+            
+            return True
+        except ConsoleTimeoutError as e:
+            LOGGER.warning(f"Timed out while getting information from the Dedicated Server: {str(e)}")
+            return False
+    
+    def save_game(self, name=None):
+        """
+            Saves the game instantly.
+            
+            Arguments:
+                - [name]: Filename to save the current savegame as
+            
+            Returns: A boolean indicating the success
+        """
+        
+        if not self.rcon.connected or (self.status != ServerStatus.RUNNING):
+            return False
+        
+        res = self.rcon.DSSaveGame(name)
+        
+        return res == True
+    
+    def load_game(self, save_name, force=False):
+        """
+            Loads the savegame specified in {save_game}.
+            
+            Arguments:
+                - save_name: Name of the savegame to load
+                - [force]: Wether to send the command without checking the current saves list first
+            
+            Returns: A boolean indicating the success
+        """
+        
+        if not self.rcon.connected or (self.status != ServerStatus.RUNNING):
+            return False
+        
+        # If force is false, check that {save_name} is actually in the save game list
+        if not force:
+            if self.curr_game_list is None:
+                raise TypeError("The current game list is None")
+            
+            found = False
+            
+            for game in self.curr_game_list.gameList:
+                if game.name == save_name:
+                    found = True
+            
+            if not found:
+                LOGGER.debug("Savegame not found")
+                return False
+        
+        if not pathvalidate.is_valid_filename(save_name):
+            raise ValueError(f"'{save_name}' is not a valid savegame name")
+        
+        if (self.curr_game_list is not None) and (save_name == self.curr_game_list.activeSaveName):
+            LOGGER.debug("Save is already loaded, RCON command would fail, aborting")
+            return False
+        
+        res = self.rcon.DSLoadGame(save_name)
+        
+        if not isinstance(res, dict):
+            LOGGER.debug(f"DSLoadGame result was not a dict: {str(res)}")
+            return False
+        
+        if "status" not in res:
+            LOGGER.debug(f"DSLoadGame response didn't conmtain 'status' key: {str(res)}")
+            return False
+        
+        if res["status"] == True:
+            return True
+        else:
+            LOGGER.debug(f"DSLoadGame failed: {res['_message']}")
+            return False
+    
+    def new_game(self, name=None):
+        """
+            Starts a new savegame.
+            
+            WARNING: Currently crashes the server running under wine
+            
+            Returns: A boolean indicating the success
+        """
+        
+        # Prevent people from using this
+        LOGGER.warning("Starting a new save game has been disabled currently due to the dedicated server crashing under wine while performing the operation")
+        LOGGER.warning("Please create new save games from inside the game")
+        return False
+        
+        if not self.rcon.connected or (self.status != ServerStatus.RUNNING):
+            return False
+        
+        res1 = self.rcon.DSNewGame(name)
+        res2 = self.rcon.DSSaveGame(name)
+        
+        return (res1 == True) and (res2 == True)
+
+class AstroDedicatedServer(DedicatedServerConsole):
+    
+    def __init__(self, launcher):
+        self.astro_path = launcher.config.AstroServerPath
+        self.wine_exec = launcher.wineexec
+        self.wineserver_exec = launcher.wineserverexec
+        self.wine_pfx = launcher.config.WinePrefixPath
+        self.use_wine = launcher.config.UseWine
+        self.executable = launcher.get_ds_executable()
+        
+        # Load configuration
+        ds_config_path = path.join(self.astro_path, ASTRO_DS_CONFIG_PATH, "AstroServerSettings.ini")
+        engine_config_path = path.join(self.astro_path, ASTRO_DS_CONFIG_PATH, "Engine.ini")
+        
+        self.ds_config = DedicatedServerConfig.ensure_config(ds_config_path, launcher.config.OverwritePublicIP, launcher.config.OverrideConsolePort, launcher.config.PublicIP)
+        self.engine_config = EngineConfig.ensure_config(engine_config_path, launcher.config.DisableEncryption, launcher.config.OverrideServerPort)
+        
+        LOGGER.debug(f"Dedicated Server configuration (including overrides):\n{json.dumps(redact_secrets(self.ds_config.to_dict(encode_json=True)), indent=4)}")
+        LOGGER.debug(f"Engine configuration (including overrides):\n{json.dumps(self.engine_config.to_dict(encode_json=True), indent=4)}")
+        
+        # Warning, if encryption is enables
+        if self.engine_config.AllowEncryption:
+            if self.use_wine:
+                LOGGER.warning("Encryption is enabled. Currently, this doesn't work when running the Astroneer Dedicated Server using WINE")
+            
+            LOGGER.warning("Players that have encryption disabled will also ne be able to play on a server having encryption enabled")
+        else:
+            LOGGER.info("NOTICE: Encryption is disabled. All players that want to join the Dedicated Server have to disable encryption on their clients too")
+        
+        # RCON
+        super().__init__(launcher, AstroRCON(self.ds_config.ConsolePort, self.ds_config.ConsolePassword, timeout=launcher.config.ConsoleCommandTimeout, keepalive=launcher.config.ConsoleKeepAlive, command_names=get_command_names(launcher.config.ConsoleCommandNames)))
+        
+        # DS Process related
+        self.process = None
+        self.process_out_queue = Queue()
+        self.process_out_thread = None
+        self.reported_errors = set()
+        
+        # File the raw output of the server process is written to, if enabled
+        self.server_log = None
+        
+        # XAuth for playfab API
+        self.curr_xauth = None
+        self.time_last_xauth = None
+        
+        # Information about Playfab registration
+        self.registered = False
+        self.lobby_id = None
+        
+        # Scheduled shutdown/restart using a countdown
+        self.shutdown_time = None
+        self.shutdown_warnings = []
+        self.shutdown_restart = False
+        
+        # Wether the server should be started again after it has shut down
+        self.restart_pending = False
+        
+        # Time of the last shutdown request and how far stopping the server has been escalated since (0: None, 1: Terminated, 2: Killed)
+        self.shutdown_request_time = None
+        self.shutdown_escalation = 0
+        
+        # Times of automatic restarts after crashes, used for detecting crash loops
+        self.crash_restart_times = []
+        
+        # Event or server error, that requested a restart and hasn't been handled yet, and the time of the last restart caused by one
+        self.event_restart_trigger = None
+        self.last_event_restart = None
+        
+        # Resource monitoring of the server processes
+        self.monitored_processes = {}
+        self.last_resource_check = None
+        self.memory_warning_active = False
+        
+        # Scheduled backups of the savegames
+        self.last_backup = None
+        
+        # Periodic saving of the game
+        self.last_autosave = None
+        
+        # Wether to enable the whitelist after the next start regardless of its saved setting
+        self.maintenance_mode = False
+    
+    def reload_ds_config(self):
+        """ Reads the configuration file for the Dedicated Server again """
+        
+        ds_config_path = path.join(self.astro_path, ASTRO_DS_CONFIG_PATH, "AstroServerSettings.ini")
+        self.ds_config = DedicatedServerConfig.ensure_config(ds_config_path, self.launcher.config.OverwritePublicIP, self.launcher.config.OverrideConsolePort, self.launcher.config.PublicIP)
+    
+    def set_server_setting(self, key, value):
+        """
+            Changes a setting of the Dedicated Server and saves it to the configuration file, such that it is kept across restarts
+            
+            Arguments:
+                - key: The name of the setting in AstroServerSettings.ini
+                - value: The new value of the setting
+        """
+        
+        ds_config_path = path.join(self.astro_path, ASTRO_DS_CONFIG_PATH, "AstroServerSettings.ini")
+        DedicatedServerConfig.update_config(ds_config_path, **{key: value})
+        setattr(self.ds_config, key, value)
+        
+        LOGGER.debug(f"Changed Dedicated Server setting '{key}'")
+        
+        # Don't send secret values with the event
+        event_value = redact_secrets({key: value})[key]
+        self.launcher.notifications.send_event(EventType.SERVER_CONFIG_CHANGE, setting=key, value=event_value, server_version=self.build_version)
+    
+    def reload_player_config(self):
+        """
+            Applies the whitelist setting and the player categories from AstroServerSettings.ini to the running Dedicated Server using its console,
+            as the server only reads them at start. This is useful after editing the file directly
+            
+            Returns:
+                - Success (bool): Wether all changes were accepted by the server
+                - Changes (int): The number of changes sent to the server
+        """
+        
+        if not self.rcon.connected or (self.status != ServerStatus.RUNNING):
+            return False, 0
+        
+        ds_config_path = path.join(self.astro_path, ASTRO_DS_CONFIG_PATH, "AstroServerSettings.ini")
+        
+        try:
+            ds_config = DedicatedServerConfig.read_config(ds_config_path)
+        except Exception as e:
+            LOGGER.error(f"Could not read Dedicated Server config: {str(e)}")
+            return False, 0
+        
+        if ds_config is None:
+            LOGGER.error("Dedicated Server config not found")
+            return False, 0
+        
+        success = True
+        changes = 0
+        
+        if (self.curr_server_stat is None) or (self.curr_server_stat.isEnforcingWhitelist != ds_config.DenyUnlistedPlayers):
+            changes += 1
+            
+            if not self.set_whitelist_enabled(ds_config.DenyUnlistedPlayers):
+                LOGGER.warning(f"The Dedicated Server didn't accept {'enabling' if ds_config.DenyUnlistedPlayers else 'disabling'} the whitelist")
+                success = False
+        
+        for entry in ds_config.PlayerProperties:
+            # The owner and pending categories can't be set using the console
+            if entry.PlayerCategory in [PlayerCategory.OWNER, PlayerCategory.PENDING]:
+                continue
+            
+            player_info = self.get_player_info(guid=entry.PlayerGuid) if entry.PlayerGuid else None
+            
+            if (player_info is not None) and (player_info.playerCategory == entry.PlayerCategory):
+                continue
+            
+            name = player_info.playerName if player_info is not None else (entry.PlayerRecentJoinName or entry.PlayerFirstJoinName)
+            
+            if not name:
+                continue
+            
+            changes += 1
+            res = self.rcon.DSSetPlayerCategoryForPlayerName(name, entry.PlayerCategory)
+            
+            if not (isinstance(res, dict) and res.get("status")):
+                LOGGER.warning(f"The Dedicated Server didn't accept setting the category of '{name}' to '{entry.PlayerCategory.value}' (Response: {res})")
+                success = False
+        
+        self.ds_config.DenyUnlistedPlayers = ds_config.DenyUnlistedPlayers
+        self.ds_config.PlayerProperties = ds_config.PlayerProperties
+        
+        # Get the new state from the server with the next status update
+        self.last_server_status = None
+        
+        if success:
+            self.launcher.notifications.send_event(EventType.CONFIG_RELOADED, changes=changes, server_version=self.build_version)
+        
+        return success, changes
+    
+    def server_loop(self):
+        """
+            Loop to run while dedicated server is running that receives/sends data, executes commands and more
+        """
+        
+        while True:
+            # Exit loop, if server is off
+            if self.status == ServerStatus.OFF:
+                break
+            
+            # If RCON is not connected, try to connect
+            if not self.rcon.connected:
+                conn = self.rcon.ensureConnection()
+                
+                # After connecting, toggle whiltelist quickly
+                if conn:
+                    self.quick_toggle_whitelist()
+                else:
+                    LOGGER.debug("Failed to connect RCON")
+            
+            # Check server process status
+            proc_status = self.process.poll()
+            if proc_status is not None:
+                # Stopping the server forcefully after it didn't shut down in time is not a crash
+                stopped = (self.status == ServerStatus.STOPPING and proc_status == 0) or (self.shutdown_escalation > 0)
+                
+                if stopped and self.restart_pending:
+                    if self.restart():
+                        self.launcher.status_thread.update_status(status=True, message="Server is running")
+                        continue
+                    
+                    LOGGER.error("Could not restart the Dedicated Server")
+                    self.launcher.status_thread.update_status(status=False, message="Server restart failed")
+                    break
+                
+                if self.shutdown_escalation > 0:
+                    LOGGER.warning("Dedicated Server was stopped forcefully")
+                    self.launcher.status_thread.update_status(status=False, message="Server was stopped forcefully")
+                    break
+                
+                if stopped:
+                    LOGGER.info("Dedicated Server shut down gracefully")
+                    self.launcher.status_thread.update_status(status=False, message="Server shut down gracefully")
+                    break
+                
+                if proc_status != 0:
+                    # The last lines of output might contain the reason for the crash
+                    self.handle_process_output()
+                    
+                    self.launcher.notifications.send_event(EventType.CRASH, server_version=self.build_version)
+                    self.launcher.status_thread.update_status(status=False, message="Server crashed")
+                    
+                    if self.launcher.config.AutoRestartOnCrash and self.restart_after_crash():
+                        self.launcher.status_thread.update_status(status=True, message="Server is running")
+                        continue
+                
+                # Server process has exited
+                LOGGER.debug(f"Server process closed with exit code {proc_status}")
+                break
+            else:
+                self.handle_process_output()
+            
+            # Stop the server forcefully, if it doesn't exit in time after being asked to shut down
+            if self.status == ServerStatus.STOPPING:
+                self.check_shutdown_timeout()
+            
+            # If not connected to RCON, skip following code as it requires RCON
+            if not self.rcon.connected:
+                LOGGER.debug("RCON is not connected, skipping related functionality")
+                time.sleep(self.launcher.config.ServerStatusInterval)
+                continue
+            
+            update_server_data = False
+            
+            if self.last_server_status is None:
+                LOGGER.debug("Doing initial Server status data update")
+                
+                # If we haven't requested any data yet, do it now
+                update_server_data = False
+                if not self.update_server_info():
+                    LOGGER.warning("Getting information from Dedicated Server failed!")
+                
+                # Remember the time of the attempt, such that a failing server isn't queried in every loop iteration
+                self.last_server_status = time.time()
+            
+            elif (time.time() - self.last_server_status) >= self.launcher.config.ServerStatusInterval:
+                # If the time interval since the last status update is big wnough, do another one
+                update_server_data = True
+                self.last_server_status = time.time()
+            
+            # Ensure XAuth is present
+            if update_server_data:
+                try:
+                    self.get_XAuth()
+                except Exception as e:
+                    LOGGER.error(str(e))
+            
+            #TODO: Get info from Playfab API
+            
+            #
+            # Update Server status data and compare to previous data
+            # to get joined and left players aswell as savegame changes
+            #
+            
+            if update_server_data and not (self.status == ServerStatus.STOPPING):                
+                try:
+                    prev_online_players = [pi for pi in self.curr_player_list.playerInfo if pi.inGame]
+                    prev_online_player_guids = [pi.playerGuid for pi in prev_online_players]
+                    
+                    prev_active_save_name = self.curr_game_list.activeSaveName
+                    
+                    if (prev_active_save_name is not None) and (prev_active_save_name != ""):
+                        save_date_list = [gi.date for gi in self.curr_game_list.gameList if gi.name == prev_active_save_name]
+                        prev_active_save_time = save_date_list[0] if len(save_date_list) > 0 else 0
+                    else:
+                        prev_active_save_time = 0
+                    
+                    if self.update_server_info():
+                        online_players = [pi for pi in self.curr_player_list.playerInfo if pi.inGame]
+                        online_player_guids = [pi.playerGuid for pi in online_players]
+                        
+                        # If the amount of players now is greater than before the update, players have joined
+                        if len(online_players) > len(prev_online_players):
+                            # Get difference of Player GUIDs to find out, who joined
+                            player_diff_guid = list(set(online_player_guids) - set(prev_online_player_guids))
+                            
+                            # Maybe redundant check
+                            if len(player_diff_guid) > 0:
+                                player_diff = [{"name": pi.playerName, "guid": pi.playerGuid} for pi in self.curr_player_list.playerInfo if pi.playerGuid in player_diff_guid]
+                                
+                                for info in player_diff:
+                                    self.launcher.notifications.send_event(EventType.PLAYER_JOIN, player_name=info["name"], player_guid=info["guid"], player_count=len(online_players), max_players=self.curr_server_stat.maxInGamePlayers, server_version=self.build_version)
+                                    
+                                    #TODO: Maybe set players to pending with command and refresh config file
                         
-                        if not success:
-                            CMD_LOGGER.warning("There was a problem while shutting down the dedicated server")
+                        # If the amount of players now is smaller than before the update, players have left
+                        if len(prev_online_players) > len(online_players):
+                            # Get difference of Player GUIDs to find out, who left
+                            player_diff_guid = list(set(prev_online_player_guids) - set(online_player_guids))
+                            
+                            # Maybe redundant check
+                            if len(player_diff_guid) > 0:
+                                player_diff = [{"name": pi.playerName, "guid": pi.playerGuid} for pi in self.curr_player_list.playerInfo if pi.playerGuid in player_diff_guid]
+                                
+                                for info in player_diff:
+                                    self.launcher.notifications.send_event(EventType.PLAYER_LEAVE, player_name=info["name"], player_guid=info["guid"], player_count=len(online_players), max_players=self.curr_server_stat.maxInGamePlayers, server_version=self.build_version)
                         
-                    elif args["cmd"] == ConsoleParser.Command.RESTART:
-                        #TODO: IMPLEMENT
-                        CMD_LOGGER.warning("The restart command is not implemented yet")
+                        # Get current savegame information
+                        active_save_name = self.curr_game_list.activeSaveName
                         
-                    elif args["cmd"] == ConsoleParser.Command.INFO:
-                        if self.curr_server_stat is not None:
-                            CMD_LOGGER.info("Information about the Dedicated Server:")
-                            CMD_LOGGER.info(f"    - Build: {self.curr_server_stat.build}")
-                            CMD_LOGGER.info(f"    - Server URL: {self.curr_server_stat.serverURL}")
-                            CMD_LOGGER.info(f"    - Owner: {self.curr_server_stat.ownerName}")
-                            CMD_LOGGER.info(f"    - Has Password: {'yes' if self.curr_server_stat.hasServerPassword else 'no'}")
-                            CMD_LOGGER.info(f"    - Whitelist: {'enabled' if self.curr_server_stat.isEnforcingWhitelist else 'disabled'}")
-                            CMD_LOGGER.info(f"    - Creative Mode: {'yes' if self.curr_server_stat.creativeMode else 'no'}")
-                            CMD_LOGGER.info(f"    - Save Game: {self.curr_server_stat.saveGameName}")
-                            CMD_LOGGER.info(f"    - Players: {len(self.curr_player_list.playerInfo)}/{self.curr_server_stat.maxInGamePlayers}")
-                            CMD_LOGGER.info(f"    - Average FPS: {self.curr_server_stat.averageFPS}")
+                        if (active_save_name is not None) and (active_save_name != ""):
+                            save_date_list = [gi.date for gi in self.curr_game_list.gameList if gi.name == active_save_name]
+                            active_save_time = save_date_list[0] if len(save_date_list) > 0 else 0
                         else:
-                            CMD_LOGGER.info("Server information not available right now")
-
-                    elif args["cmd"] == ConsoleParser.Command.KICK:
-                        self.kick_player(name=args["player"], guid=args["player"])
-
-                    elif args["cmd"] == ConsoleParser.Command.WHITELIST:
-                        if args["subcmd"] == ConsoleParser.WhitelistSubcommand.ENABLE:
-                            success = self.set_whitelist_enabled(True)
-                            
-                            if success:
-                                CMD_LOGGER.info("Successfully enabled whitelist")
-                        elif args["subcmd"] == ConsoleParser.WhitelistSubcommand.DISABLE:
-                            success = self.set_whitelist_enabled(False)
-                            
-                            if success:
-                                CMD_LOGGER.info("Successfully disabled whitelist")
-                        elif args["subcmd"] == ConsoleParser.WhitelistSubcommand.STATUS:
-                            success = True
-                            CMD_LOGGER.info(f"The whitelist is currently {'enabled' if self.curr_server_stat.isEnforcingWhitelist else 'disabled'}")
+                            active_save_time = 0
                         
-                        if not success:
-                            CMD_LOGGER.warning("There was a problem while setting the whitelist status")
-
-                    elif args["cmd"] == ConsoleParser.Command.LIST:
-                        if self.curr_player_list is not None:
-                            if args["category"] == ConsoleParser.ListCategory.ALL:
-                                category = None
-                            else:
-                                category = PlayerCategory[args["category"].name]
+                        # If active save names are different, the server changed savegame
+                        if active_save_name != prev_active_save_name:
+                            self.launcher.notifications.send_event(EventType.SAVEGAME_CHANGE, savegame_name=active_save_name, server_version=self.build_version)
+                        else:
+                            # If save was not changed, check if server saved the game
+                            if active_save_time != prev_active_save_time:
+                                self.launcher.notifications.send_event(EventType.SAVE, savegame_name=active_save_name, server_version=self.build_version)
+                    else:
+                        LOGGER.error("Error while getting data from dedicated server")
+                except Exception as e:
+                    LOGGER.debug(f"Error while doing status update: {str(e)}")
+                    LOGGER.error(traceback.format_exc())
+            
+            
+            # Handle console commands in queue
+            while not self.launcher.cmd_queue.empty():                
+                args = self.launcher.cmd_queue.get()
+                
+                
+                #TODO: Change functions in server such that they return a boolean AND a message, which makes logging easier
+                
+                
+                try:
+                    if args["cmd"] == ConsoleParser.Command.SHUTDOWN:
+                        if (args["countdown"] is None) or (args["countdown"] <= 0):
+                            success = self.save_and_shutdown()
                             
-                            # Filter players based on online status and category
-                            filtered_players = []
+                            if not success:
+                                CMD_LOGGER.warning("There was a problem while shutting down the dedicated server")
+                        else:
+                            if self.shutdown_time is not None:
+                                CMD_LOGGER.info("Replacing the currently scheduled shutdown/restart")
                             
-                            for pi in self.curr_player_list.playerInfo:
-                                if pi.inGame and ((category is None) or (category == pi.playerCategory)):
-                                    filtered_players.append(pi)
+                            self.schedule_shutdown(args["countdown"])
+                            CMD_LOGGER.info(f"Shutting down the Dedicated Server in {args['countdown']} seconds")
+
+                    elif args["cmd"] == ConsoleParser.Command.RESTART:
+                        if (args["countdown"] is None) or (args["countdown"] <= 0):
+                            success = self.save_and_shutdown(restart=True)
                             
-                            # Output player info
-                            if category is None:
-                                CMD_LOGGER.info(f"Online Players ({len(filtered_players)}):")
+                            if success:
+                                CMD_LOGGER.info("Restarting the Dedicated Server...")
                             else:
-                                CMD_LOGGER.info(f"Online '{category.value}' Players ({len(filtered_players)}):")
-                            
-                            for pi in filtered_players:
-                                CMD_LOGGER.info(f"    - {pi.playerName}({pi.playerGuid})")
+                                CMD_LOGGER.warning("There was a problem while restarting the dedicated server")
                         else:
-                            CMD_LOGGER.info("Player information not available right now")
-
-                    elif args["cmd"] == ConsoleParser.Command.SAVEGAME:
-                        if args["subcmd"] == ConsoleParser.SaveGameSubcommand.LOAD:
-                            if (self.curr_game_list is not None) and (args["save_name"] == self.curr_game_list.activeSaveName):
-                                CMD_LOGGER.warning("Specified save is currently already loaded")
-                            else:
-                                try:
-                                    success = self.load_game(args["save_name"])
-                                    
-                                    if success:
-                                        CMD_LOGGER.info(f"Loading save game '{args['save_name']}'...")
-                                    else:
-                                        CMD_LOGGER.warning("There was a problem while executing the command")
-                                except Exception as e:
-                                    CMD_LOGGER.error(f"Error while executing command: {str(e)}")
-                                    CMD_LOGGER.error(traceback.format_exc())
+                            if self.shutdown_time is not None:
+                                CMD_LOGGER.info("Replacing the currently scheduled shutdown/restart")
                             
-                        if args["subcmd"] == ConsoleParser.SaveGameSubcommand.SAVE:
-                            try:
-                                success = self.save_game(args["save_name"])
-                                
-                                if success:
-                                    CMD_LOGGER.info("Saving the game...")
-                                else:
-                                    CMD_LOGGER.warning("There was a problem while executing the command")
-                            except Exception as e:
-                                CMD_LOGGER.error(f"Error while executing command: {str(e)}")
-                                CMD_LOGGER.error(traceback.format_exc())
-                                
-                        if args["subcmd"] == ConsoleParser.SaveGameSubcommand.NEW:
-                            try:
-                                success = self.new_game(args["save_name"])
-                                
-                                if success:
-                                    CMD_LOGGER.info("Creating new save game...")
-                                else:
-                                    CMD_LOGGER.warning("There was a problem while executing the command")
-                            except Exception as e:
-                                CMD_LOGGER.error(f"Error while executing command: {str(e)}")
-                                CMD_LOGGER.error(traceback.format_exc())
-                                
-                        if args["subcmd"] == ConsoleParser.SaveGameSubcommand.LIST:
-                            if self.curr_game_list is not None:
-                                
-                                CMD_LOGGER.info("Savegames:")
-                                
-                                for gi in self.curr_game_list.gameList:
-                                    CMD_LOGGER.info(f"    - {gi.name} [{gi.date}]  Creative: {gi.bHasBeenFlaggedAsCreativeModeSave}")
-                            else:
-                                CMD_LOGGER.info("Savegame information not available right now")
-
-                    elif args["cmd"] == ConsoleParser.Command.PLAYER:
-                        if args["subcmd"] == ConsoleParser.PlayerSubcommand.SET:
-                            res = self.set_player_category(category=PlayerCategory[args["category"].name], name=args["player"], guid=args["player"])
+                            self.schedule_shutdown(args["countdown"], restart=True)
+                            CMD_LOGGER.info(f"Restarting the Dedicated Server in {args['countdown']} seconds")
+                    
+                    elif args["cmd"] == ConsoleParser.Command.SERVERCONFIG:
+                        if args["subcmd"] == ConsoleParser.ServerConfigSubcommand.GET:
+                            CMD_LOGGER.info("Dedicated Server settings:")
+                            CMD_LOGGER.info(f"    - Server Name: {self.ds_config.ServerName}")
+                            CMD_LOGGER.info(f"    - Password: {'set' if self.ds_config.ServerPassword else 'not set'}")
+                        elif args["subcmd"] == ConsoleParser.ServerConfigSubcommand.SET_NAME:
+                            self.set_server_setting("ServerName", args["name"])
+                            CMD_LOGGER.info(f"Changed server name to '{args['name']}'")
+                        elif args["subcmd"] == ConsoleParser.ServerConfigSubcommand.SET_PASSWORD:
+                            password = args["password"] if args["password"] else ""
+                            self.set_server_setting("ServerPassword", password)
+                            CMD_LOGGER.info("Changed server password" if password else "Removed server password")
                             
-                            if res is None:
-                                CMD_LOGGER.warning("Specified player was not found")
-                            elif res == True:
-                                CMD_LOGGER.info("Successfully changed player category")
-                            else:
-                                CMD_LOGGER.info("There was a problem while executing the command")
+                            # Don't send the password with the command event. The command line is built from the arguments again,
+                            # as replacing the password in it would also replace other parts, that happen to contain it
+                            if password:
+                                args["cmdline"] = f"{ConsoleParser.Command.SERVERCONFIG.value} {ConsoleParser.ServerConfigSubcommand.SET_PASSWORD.value} {REDACTED}"
+                        
+                        if args["subcmd"] != ConsoleParser.ServerConfigSubcommand.GET:
+                            # The console of the Dedicated Server doesn't support changing these settings while it is running
+                            CMD_LOGGER.warning("The change was saved to AstroServerSettings.ini and takes effect after the Dedicated Server is restarted. Use 'restart' to apply it")
+
+                    elif args["cmd"] == ConsoleParser.Command.UPTIME:
+                        if self.registered_time is None:
+                            CMD_LOGGER.info("The Dedicated Server is not running right now")
+                        else:
+                            now = time.time()
                             
-                        elif args["subcmd"] == ConsoleParser.PlayerSubcommand.GET:
-                            player_info = self.get_player_info(name=args["player"], guid=args["player"])
+                            CMD_LOGGER.info(f"Uptime since the last restart: {format_duration(now - self.registered_time)}")
+                            CMD_LOGGER.info(f"Uptime since the first start: {format_duration(now - self.session_start_time)} ({self.restart_count} restarts)")
+
+                    elif args["cmd"] == ConsoleParser.Command.RELOAD:
+                        success, changes = self.reload_player_config()
+                        
+                        if success:
+                            CMD_LOGGER.info(f"Reloaded whitelist and player categories from AstroServerSettings.ini ({changes} changes applied)")
+                        else:
+                            CMD_LOGGER.warning(f"There was a problem while reloading whitelist and player categories ({changes} changes attempted)")
+
+                    elif args["cmd"] == ConsoleParser.Command.MAXPLAYERS:
+                        if args["count"] is None:
+                            CMD_LOGGER.info(f"Maximum players: {self.ds_config.MaximumPlayerCount}")
                             
-                            if player_info:
-                                CMD_LOGGER.info("Player Information:")
-                                CMD_LOGGER.info(f"  - Name: {player_info.playerName}")
-                                CMD_LOGGER.info(f"  - GUID: {player_info.playerGuid}")
-                                CMD_LOGGER.info(f"  - Category: {player_info.playerCategory.value}")
-                                CMD_LOGGER.info(f"  - Online: {'yes' if player_info.inGame else 'no'}")
-                            else:
-                                CMD_LOGGER.info("Player information not available right now")
+                            if (self.curr_server_stat is not None) and (self.curr_server_stat.maxInGamePlayers != self.ds_config.MaximumPlayerCount):
+                                CMD_LOGGER.info(f"The running Dedicated Server still uses a maximum of {self.curr_server_stat.maxInGamePlayers} players until it is restarted")
+                        elif not (MIN_PLAYER_COUNT <= args["count"] <= MAX_PLAYER_COUNT):
+                            CMD_LOGGER.warning(f"The maximum player count has to be between {MIN_PLAYER_COUNT} and {MAX_PLAYER_COUNT}")
+                        else:
+                            self.set_server_setting("MaximumPlayerCount", args["count"])
+                            CMD_LOGGER.info(f"Changed maximum players to {args['count']}")
+                            CMD_LOGGER.warning("The change was saved to AstroServerSettings.ini and takes effect after the Dedicated Server is restarted. Use 'restart' to apply it")
+                    
+                    else:
+                        self.execute_command(args)
                     
                     # Send notification event after executing command
                     self.launcher.notifications.send_event(EventType.COMMAND, command=args["cmdline"], server_version=self.build_version)
+                except ConsoleTimeoutError as e:
+                    CMD_LOGGER.error(f"Command timed out: {str(e)}")
                 except Exception as e:
                     CMD_LOGGER.error(f"Error occured while executing command: {str(e)}")
                     CMD_LOGGER.error(traceback.format_exc())
             
+            # Check resource usage of the server processes
+            if (self.launcher.config.ResourceMonitorInterval > 0) and ((self.last_resource_check is None) or ((time.time() - self.last_resource_check) >= self.launcher.config.ResourceMonitorInterval)):
+                self.last_resource_check = time.time()
+                self.check_resource_usage()
+            
+            # Create a backup of the savegames, if one is due
+            if (self.launcher.config.backup.BackupInterval > 0) and (self.status == ServerStatus.RUNNING):
+                if self.last_backup is None:
+                    self.last_backup = time.time()
+                elif (time.time() - self.last_backup) >= (self.launcher.config.backup.BackupInterval * 3600):
+                    self.last_backup = time.time()
+                    self.scheduled_backup()
+            
+            # Save the game, if an autosave is due
+            if (self.launcher.config.AutoSaveInterval > 0) and (self.status == ServerStatus.RUNNING):
+                if self.last_autosave is None:
+                    self.last_autosave = time.time()
+                elif (time.time() - self.last_autosave) >= (self.launcher.config.AutoSaveInterval * 60):
+                    self.last_autosave = time.time()
+                    self.autosave()
+            
+            # Handle requests received using signals, as they can't be handled safely in the signal handler
+            if self.launcher.save_requested and (self.status == ServerStatus.RUNNING):
+                self.launcher.save_requested = False
+                self.external_save()
+            
+            if self.launcher.log_roll_requested:
+                self.launcher.log_roll_requested = False
+                self.launcher.roll_logs()
+            
+            # Warn players and shut down the server, if a shutdown is scheduled
+            if (self.shutdown_time is not None) and (self.status == ServerStatus.RUNNING):
+                self.handle_scheduled_shutdown()
+            
+            # Restart the server, if one of the configured events requested it
+            if (self.event_restart_trigger is not None) and (self.status == ServerStatus.RUNNING):
+                self.handle_event_restart()
+            
             # If we didn't do an update, wait a short while. This reduces the CPU usage of the launcher
             if not update_server_data:
                 time.sleep(0.005)
@@ -780,6 +1807,10 @@ class AstroDedicatedServer:
         # Deregister all still with playfab registered servers to avoid issues
         old_lobbyIDs = self.deregister_all_servers()
         
+        # Send summary of the environment the server is started in
+        self.build_version = read_build_version(self.astro_path)
+        self.launcher.notifications.send_event(EventType.SERVER_STARTING, server_port=self.engine_config.Port, console_port=self.ds_config.ConsolePort, wine_prefix=self.wine_pfx if self.use_wine else "none", encryption="enabled" if self.engine_config.AllowEncryption else "disabled", server_version=self.build_version)
+        
         start_time = time.time()
         try:
             self.start_process()
@@ -803,15 +1834,7 @@ class AstroDedicatedServer:
         # Wait for DS to finish registration
         with alive_bar(title="Waiting for Dedicated Server to register with Playfab", spinner=AP_SPINNER, bar=None, receipt=True, enrich_print=False, monitor=False, stats=False, force_tty=CONTROL_CODES_SUPPORTED) as bar:
             while not self.registered:
-                # Print all lines currently in process output queue
-                while True:
-                    try:
-                        line = self.process_out_queue.get_nowait()
-                    except Empty:
-                        break
-                    else:
-                        line = line.replace("\n", "")   # Remove newline character, since it it unnecessary
-                        LOGGER.debug(f"[AstroDS] {line}")
+                self.handle_process_output()
                 
                 # Try to connect to RCON early to support shutdown command
                 if not self.rcon.connected:
@@ -871,37 +1894,303 @@ class AstroDedicatedServer:
         
         LOGGER.info(f"Dedicated Server ready! Took {round(elapsed, 2)} seconds to register")
         
+        self.registered_time = done_time
+        
+        if self.session_start_time is None:
+            self.session_start_time = done_time
+        
+        self.check_clock_skew()
+        
         self.status = ServerStatus.RUNNING
         
         self.launcher.notifications.send_event(EventType.START, server_version=self.build_version)
         
+        # Maintenance mode only applies to the first start, such that a later restart uses the saved setting again
+        if self.maintenance_mode:
+            self.maintenance_mode = False
+            self.enable_maintenance_mode()
+        
         return True
     
+    def enable_maintenance_mode(self):
+        """
+            Enables the whitelist regardless of its saved setting, such that only whitelisted players can join, e.g. while preparing an event
+            
+            Returns: A boolean indicating the success
+        """
+        
+        try:
+            success = self.update_server_info() and self.set_whitelist_enabled(True)
+        except ConsoleTimeoutError as e:
+            LOGGER.warning(f"Timed out while enabling the whitelist: {str(e)}")
+            success = False
+        
+        if not success:
+            LOGGER.error("Could not enable the whitelist for maintenance mode")
+            return False
+        
+        LOGGER.info("Maintenance mode: The whitelist is enabled, only whitelisted players can join. Use 'whitelist disable' to let everyone join")
+        self.launcher.notifications.send_event(EventType.MAINTENANCE_MODE, server_version=self.build_version)
+        
+        return True
+    
+    def check_clock_skew(self):
+        """ Logs a warning, if the clock of the Dedicated Server differs from the system clock, as it makes comparing logs confusing """
+        
+        skew = get_clock_skew(self.astro_path)
+        
+        if skew is None:
+            LOGGER.debug("Could not determine the clock difference to the Dedicated Server")
+        elif abs(skew) > CLOCK_SKEW_WARNING_THRESHOLD:
+            LOGGER.warning(f"The clock of the Dedicated Server is {abs(skew)} seconds {'ahead of' if skew > 0 else 'behind'} the system clock. Timestamps in its logs differ from the launcher logs")
+        else:
+            LOGGER.debug(f"Clock difference to the Dedicated Server: {skew} seconds")
+    
+    def get_command(self):
+        """
+            Returns the command line and environment used for starting the server process as a tuple
+        """
+        
+        env = os.environ.copy()
+        
+        # A native Dedicated Server executable is run directly
+        if not self.use_wine:
+            return [self.executable, "-log"], env
+        
+        cmd = [self.wine_exec, self.executable, "-log"]
+        env["WINEPREFIX"] = self.wine_pfx
+        
+        return cmd, env
+    
     def start_process(self):
         """ Start the server process and set the status to RUNNING """
         
         LOGGER.debug("Starting Dedicated Server process...")
         
-        cmd = [self.wine_exec, path.join(self.astro_path, "AstroServer.exe"), "-log"]
-        env = os.environ.copy()
-        env["WINEPREFIX"] = self.wine_pfx
+        cmd, env = self.get_command()
         
-        LOGGER.debug(f"Executing command '{' '.join(cmd)}' in WINE prefix '{self.wine_pfx}'...")
+        if self.use_wine:
+            LOGGER.debug(f"Executing command '{' '.join(cmd)}' in WINE prefix '{self.wine_pfx}'...")
+        else:
+            LOGGER.debug(f"Executing command '{' '.join(cmd)}'...")
+        
+        if self.launcher.config.ServerOutputLog and (self.server_log is None):
+            self.open_server_log()
         
         self.process = subprocess.Popen(cmd, env=env, cwd=self.astro_path, stderr=subprocess.PIPE, bufsize=1, close_fds=True, text=True)
+        self.reported_errors = set()
         
         self.process_out_thread = ProcessOutputThread(self.process.stderr, self.process_out_queue)
         self.process_out_thread.start()
         
         time.sleep(0.01)
     
-    def kill(self):
-        """ Kill the Dedicated Server process using wineserver -k """
+    def open_server_log(self):
+        """
+            Opens a new file in the log directory, that the raw output of the server process is written to.
+            Old files are compressed the same way as the launcher log files
+        """
+        
+        # Only write the server output, if the launcher writes log files too
+        if LauncherLogging.logfile_path is None:
+            return
+        
+        log_path = self.launcher.config.LogPath
+        
+        if self.launcher.config.KeepUncompressedLogs is not None:
+            LauncherLogging.compress_old_logs(log_path, self.launcher.config.KeepUncompressedLogs, base_filename=SERVER_LOG_BASE_FILENAME)
+        
+        try:
+            server_log_path = LauncherLogging.get_logfile_path(log_path, SERVER_LOG_BASE_FILENAME)
+            self.server_log = open(server_log_path, "a", encoding="utf-8")
+        except (OSError, ValueError) as e:
+            LOGGER.warning(f"Could not open file for the output of the Dedicated Server: {str(e)}")
+            return
+        
+        LOGGER.info(f"Writing output of the Dedicated Server to '{server_log_path}'")
+    
+    def close_server_log(self):
+        """ Closes the file, that the raw output of the server process is written to, if one is open """
+        
+        if self.server_log is not None:
+            try:
+                self.server_log.close()
+            except OSError as e:
+                LOGGER.warning(f"Could not close file for the output of the Dedicated Server: {str(e)}")
+            
+            self.server_log = None
+    
+    def handle_process_output(self):
+        """
+            Logs all lines currently in the process output queue and reports known problems found in them as SERVER_ERROR events
+        """
+        
+        while True:
+            try:
+                line = self.process_out_queue.get_nowait()
+            except Empty:
+                break
+            else:
+                # Write the output as-is before it is processed
+                if self.server_log is not None:
+                    try:
+                        self.server_log.write(line)
+                        self.server_log.flush()
+                    except OSError as e:
+                        LOGGER.warning(f"Could not write output of the Dedicated Server to file, not writing it anymore: {str(e)}")
+                        self.server_log = None
+                
+                line = line.replace("\n", "")   # Remove newline character, since it it unnecessary
+                LOGGER.debug(f"[AstroDS] {line}")
+                
+                for error_id, pattern, message in SERVER_ERROR_PATTERNS:
+                    # Only report every problem once per server process, as they are often repeated many times
+                    if (error_id not in self.reported_errors) and pattern.search(line):
+                        self.reported_errors.add(error_id)
+                        self.launcher.notifications.send_event(EventType.SERVER_ERROR, message=message, error=error_id, line=line, server_version=self.build_version)
+    
+    def restart(self):
+        """
+            Starts the dedicated server again after it has been shut down for a restart.
+            Cleans up the state of the previous server process first.
+            
+            Returns: A boolean indicating the success
+        """
+        
+        self.restart_pending = False
+        self.shutdown_request_time = None
+        self.shutdown_escalation = 0
+        self.event_restart_trigger = None
+        
+        # Kill remaining wine processes and reset state of previous process
+        self.kill()
+        self.rcon.disconnect()
+        
+        self.registered = False
+        self.registered_time = None
+        self.lobby_id = None
+        self.last_server_status = None
+        
+        LOGGER.info("Restarting the Dedicated Server...")
+        
+        try:
+            if not self.start():
+                return False
+        except Exception as e:
+            LOGGER.error(f"There as an error while restarting the Dedicated Server: {str(e)}")
+            return False
+        
+        self.restart_count += 1
+        self.launcher.notifications.send_event(EventType.RESTARTED, server_version=self.build_version)
+        
+        return True
+    
+    def restart_after_crash(self):
+        """
+            Restarts the dedicated server after it has crashed.
+            If the server has been restarted too often within the configured window, no further restarts are attempted
+            and the launcher waits idle for the user to intervene instead.
+            Failed restarts are retried with an increasing delay, up to CRASH_RESTART_MAX_ATTEMPTS times in a row.
+            
+            Returns: A boolean indicating, wether the server is running again
+        """
+        
+        failed_attempts = 0
+        
+        while True:
+            now = time.time()
+            window = self.launcher.config.CrashLoopWindow
+            
+            # Only keep restarts inside of the sliding window
+            self.crash_restart_times = [t for t in self.crash_restart_times if (now - t) < window]
+            
+            if len(self.crash_restart_times) >= self.launcher.config.CrashLoopMaxRestarts:
+                self.launcher.notifications.send_event(EventType.CRASH_LOOP, restarts=len(self.crash_restart_times), window=window, server_version=self.build_version)
+                
+                if not self.idle():
+                    return False
+                
+                self.crash_restart_times = []
+            
+            self.crash_restart_times.append(time.time())
+            
+            LOGGER.info("Automatically restarting the Dedicated Server after crash...")
+            
+            if self.restart():
+                return True
+            
+            failed_attempts += 1
+            
+            if failed_attempts >= CRASH_RESTART_MAX_ATTEMPTS:
+                LOGGER.error(f"Could not restart the Dedicated Server after {failed_attempts} attempts")
+                
+                if not self.idle():
+                    return False
+                
+                failed_attempts = 0
+                self.crash_restart_times = []
+                continue
+            
+            delay = CRASH_RESTART_RETRY_DELAY * (2 ** (failed_attempts - 1))
+            
+            LOGGER.error(f"Could not restart the Dedicated Server, trying again in {delay} seconds")
+            time.sleep(delay)
+    
+    def idle(self):
+        """
+            Waits for the user to either start the dedicated server again or quit, while the server is not running
+            
+            Returns: A boolean indicating, wether the server should be started again
+        """
+        
+        # Make sure no server processes remain
+        self.kill()
+        
+        LOGGER.warning("The Dedicated Server is not running. Enter 'restart' to start it again or 'shutdown' to quit")
+        
+        while True:
+            while not self.launcher.cmd_queue.empty():
+                args = self.launcher.cmd_queue.get()
+                
+                if args["cmd"] == ConsoleParser.Command.RESTART:
+                    return True
+                elif args["cmd"] == ConsoleParser.Command.SHUTDOWN:
+                    return False
+                else:
+                    CMD_LOGGER.warning("The Dedicated Server is not running right now")
+            
+            time.sleep(0.1)
+    
+    def kill(self, set_off=True):
+        """
+            Kill the Dedicated Server process using wineserver -k or directly, if it is not run using WINE
+            
+            Arguments:
+                - [set_off]: Wether the status should be set to OFF afterwards
+        """
         
         # Stop reading thread
         if self.process_out_thread:
             self.process_out_thread.stop()
         
+        # The output of the process is complete
+        self.close_server_log()
+        
+        if not self.use_wine:
+            if self.process and (self.process.poll() is None):
+                LOGGER.debug("Terminating Dedicated Server process...")
+                self.process.terminate()
+                
+                try:
+                    self.process.wait(timeout=15)
+                except subprocess.TimeoutExpired:
+                    LOGGER.warning("Server took longer than 15 seconds to terminate, killing it")
+                    self.process.kill()
+            
+            if set_off:
+                self.status = ServerStatus.OFF
+            return
+        
         cmd = [self.wineserver_exec, "-k", "-w"]
         env = os.environ.copy()
         env["WINEPREFIX"] = self.wine_pfx
@@ -915,24 +2204,11 @@ class AstroDedicatedServer:
             LOGGER.warning("Server took longer than 15 seconds to kill, killing wineserver")
             process.kill()
         
-        self.status = ServerStatus.OFF
+        if set_off:
+            self.status = ServerStatus.OFF
     
     # Server interaction methods (RCON)
     
-    def get_player_info(self, name=None, guid=None):
-        """ Get the PlayerInfo object related to the player whose name or GUID match """
-        
-        if (name is None) and (guid is None):
-            raise ValueError("One of name, guid has to be provided")
-        
-        if not self.curr_player_list:
-            return None
-        
-        for player_info in self.curr_player_list.playerInfo:
-            if ((name and player_info.playerName == name)
-                or (guid and player_info.playerGuid == guid)):
-                return player_info
-    
     def shutdown(self):
         """
             Shut down the dedicated server by sending it the DSServerShutdown command.
@@ -950,264 +2226,270 @@ class AstroDedicatedServer:
             self.curr_server_stat = None
             self.curr_player_list = None
             self.curr_game_list = None
-        
+            
             self.status = ServerStatus.STOPPING
-        
+            
+            self.shutdown_request_time = time.time()
+            self.shutdown_escalation = 0
+            
             return True
         else:
             return False
-
-    def set_player_category(self, category, name=None, guid=None, force=False):
+    
+    def check_shutdown_timeout(self):
         """
-            Sets the category of the player identified by either the name or guid.
-            
-            Arguments:
-                - category: A rcon.PlayerCategory to set
-                - name/guid: Name/GUID to identify the Player
-                - force: Wether to send the command without checking the player list first.
-                    Only works, if {name} is set
-
-            Returns: A boolean indicating the success or None if the player was not found
+            Terminates the server process, if it hasn't exited ShutdownGraceTimeout seconds after being asked to shut down,
+            and kills it, if it still hasn't exited SHUTDOWN_TERMINATE_TIMEOUT seconds after that
         """
         
-        if (name is None) and (guid is None):
-            raise ValueError("One of name, guid has to be provided")
+        grace_timeout = self.launcher.config.ShutdownGraceTimeout
         
-        if not self.rcon.connected or (self.status != ServerStatus.RUNNING):
-            return False
+        if (grace_timeout <= 0) or (self.shutdown_request_time is None):
+            return
         
-        if force:
-            if name is None:
-                raise ValueError("force=True can only be used if a name is given")
-            
-            res = self.rcon.DSSetPlayerCategoryForPlayerName(name, category)
-        else:
-            player_info = self.get_player_info(name=name, guid=guid)
+        elapsed = time.time() - self.shutdown_request_time
+        
+        if (self.shutdown_escalation == 0) and (elapsed >= grace_timeout):
+            LOGGER.warning(f"Dedicated Server didn't shut down within {grace_timeout} seconds, terminating it...")
+            self.shutdown_escalation = 1
+            self.process.terminate()
+        elif (self.shutdown_escalation == 1) and (elapsed >= grace_timeout + SHUTDOWN_TERMINATE_TIMEOUT):
+            LOGGER.warning(f"Dedicated Server didn't exit within {SHUTDOWN_TERMINATE_TIMEOUT} seconds after terminating it, killing it...")
+            self.shutdown_escalation = 2
+            # Keep the status, such that the server loop can still restart the server or report the forced stop
+            self.kill(set_off=False)
+            self.process.kill()
+    
+    def save_and_shutdown(self, restart=False):
+        """
+            Saves the game and shuts down the dedicated server afterwards.
+            If saving the game fails, the server is still shut down.
             
-            if player_info is None:
-                return None
+            Arguments:
+                - [restart]: Wether the server should be started again after it has shut down
             
-            res = self.rcon.DSSetPlayerCategoryForPlayerName(player_info.playerName, category)
+            Returns: A boolean indicating the success of the shutdown
+        """
         
-        if not isinstance(res, dict):
-            return False
+        if self.save_game():
+            LOGGER.info("Saved the game before shutting down")
+        else:
+            LOGGER.warning("Could not save the game before shutting down")
         
-        return res["status"]
+        success = self.shutdown()
+        
+        if success:
+            self.restart_pending = restart
+        
+        return success
     
-    def set_whitelist_enabled(self, enabled=True):
+    def request_event_restart(self, trigger):
         """
-            Changes the enables state of the Whitelist
+            Requests a restart of the dedicated server because of an event configured in RestartOnEvents.
+            The restart is done by the server loop, as events can be sent from any thread.
             
             Arguments:
-                - enabled: Wether to enable/disable the whitelist
-            
-            Returns: A boolean indicating the success
+                - trigger: The event type or server error ID, that caused the request
         """
         
-        if not self.rcon.connected or (self.status != ServerStatus.RUNNING):
-            return False
+        self.event_restart_trigger = trigger
+    
+    def handle_event_restart(self):
+        """ Restarts the dedicated server because of a requested restart on an event, unless the cooldown since the last one hasn't run out yet """
         
-        if self.curr_server_stat and (self.curr_server_stat.isEnforcingWhitelist == enabled):
-            return True
+        trigger = self.event_restart_trigger
+        self.event_restart_trigger = None
         
-        res = self.rcon.DSSetDenyUnlisted(enabled)
+        # Don't interfere with a restart or shutdown, that is already happening
+        if self.restart_pending or (self.shutdown_request_time is not None):
+            return
         
-        if not isinstance(res, bytes):
-            return False
+        cooldown = self.launcher.config.RestartOnEventCooldown
         
-        res = res.decode()
-        if res[:67] == "UAstroServerCommExecutor::DSSetDenyUnlisted: SetDenyUnlistedPlayers" and res[-1:] == "1":
-            self.curr_server_stat.isEnforcingWhitelist = enabled
-            return True
-        else:
-            return False
+        if (self.last_event_restart is not None) and ((time.time() - self.last_event_restart) < cooldown):
+            LOGGER.warning(f"Not restarting the Dedicated Server because of '{trigger}', as the last restart caused by an event was less than {cooldown} seconds ago")
+            return
+        
+        self.last_event_restart = time.time()
+        
+        LOGGER.warning(f"Restarting the Dedicated Server, because '{trigger}' was detected")
+        self.launcher.notifications.send_event(EventType.RESTART_ON_EVENT, trigger=trigger, server_version=self.build_version)
+        
+        if not self.save_and_shutdown(restart=True):
+            LOGGER.warning("There was a problem while restarting the dedicated server")
     
-    def kick_player(self, guid=None, name=None, force=False):
+    def schedule_shutdown(self, countdown, restart=False):
         """
-            Kicks the player identified by name/guid.
+            Schedules a shutdown of the dedicated server in {countdown} seconds.
+            Players are warned at the remaining times in SHUTDOWN_WARNING_TIMES.
             
             Arguments:
-                - name/guid: Name/GUID to identify the Player
-                - force: Wether to send the command without checking the player list first.
-                    Only works, if {guid} is set
-            
-            Returns: A boolean indicating the success
+                - countdown: The time in seconds after which the server should be shut down
+                - [restart]: Wether the server should be started again after it has shut down
         """
         
-        if (name is None) and (guid is None):
-            raise ValueError("One of name, guid has to be provided")
+        self.shutdown_time = time.time() + countdown
+        self.shutdown_warnings = [t for t in SHUTDOWN_WARNING_TIMES if t < countdown]
+        self.shutdown_restart = restart
         
-        if not self.rcon.connected or (self.status != ServerStatus.RUNNING):
-            return False
+        if self.launcher.config.ShutdownGraceTimeout > 0:
+            LOGGER.info(f"The Dedicated Server will be stopped forcefully, if it doesn't shut down within {self.launcher.config.ShutdownGraceTimeout} seconds after the countdown")
         
-        if force:
-            if guid is None:
-                raise ValueError("force=True can only be used if a guid is given")
-            
-            res = self.rcon.DSKickPlayerGuid(guid)
-        else:
-            player_info = self.get_player_info(name=name, guid=guid)
+        self.warn_players(countdown)
+    
+    def handle_scheduled_shutdown(self):
+        """ Sends due warnings of a scheduled shutdown and shuts the server down once the countdown has run out """
+        
+        remaining = self.shutdown_time - time.time()
+        
+        if remaining <= 0:
+            self.shutdown_time = None
+            self.shutdown_warnings = []
             
-            if player_info is None:
-                LOGGER.warning("Unknown Player")
-                return False
+            if not self.save_and_shutdown(restart=self.shutdown_restart):
+                LOGGER.warning("There was a problem while shutting down the dedicated server")
             
-            res = self.rcon.DSKickPlayerGuid(player_info.playerGuid)
-        
-        if not isinstance(res, bytes):
-            LOGGER.warning("Error while executing command")
-            return False
+            return
         
-        res = res.decode()
-        success = res[:42] == "UAstroServerCommExecutor::DSKickPlayerGuid" and res[-1:] == "d"
+        # Only send the most recent warning, if several have become due at once
+        due_warnings = [t for t in self.shutdown_warnings if t >= remaining]
         
-        if success:
-            if force:
-                LOGGER.info(f"Kicked Player with GUID '{guid}'")
-            else:
-                LOGGER.info(f"Kicked Player '{player_info.playerName}'")
-                
-            return True
-        else:
-            return success
+        if len(due_warnings) > 0:
+            self.shutdown_warnings = [t for t in self.shutdown_warnings if t < remaining]
+            self.warn_players(due_warnings[-1])
     
-    def update_server_info(self):
+    def scheduled_backup(self):
         """
-            Updates the stored information about the dedicated server
-            
-            Returns: A boolean indicating the success
+            Saves the game and creates a backup of the savegames afterwards, so the backup contains the current state of the world
         """
         
-        if not self.rcon.connected or (self.status != ServerStatus.RUNNING):
-            return False
-        
-        res = self.rcon.DSServerStatistics()
+        LOGGER.info("Saving game for scheduled backup...")
         
-        if not isinstance(res, dict):
-            return False
+        # The backup already saves the game, so the next autosave can wait
+        self.last_autosave = time.time()
         
         try:
-            self.curr_server_stat = ServerStatistics.from_dict(res)
-        except Exception as e:
-            LOGGER.error(f"Error while decoding received server statistics: {type(e)}: {str(e)}")
-            LOGGER.debug(f"Received response: {json.dumps(res)}")
+            if not self.save_game():
+                LOGGER.warning("Could not save game before backup, backing up last saved state")
+        except ConsoleTimeoutError:
+            LOGGER.warning("Saving game timed out, backing up last saved state")
         
-        res = self.rcon.DSListPlayers()
+        self.launcher.create_backup("scheduled", savegames_only=True)
+    
+    def autosave(self):
+        """ Saves the game and sends an AUTOSAVE event on success """
         
-        if not isinstance(res, dict):
-            return False
+        LOGGER.debug("Autosaving game...")
         
         try:
-            self.curr_player_list = PlayerList.from_dict(res)
-        except Exception as e:
-            LOGGER.error(f"Error while decoding received player list: {type(e)}: {str(e)}")
-            LOGGER.debug(f"Received response: {json.dumps(res)}")
+            success = self.save_game()
+        except ConsoleTimeoutError:
+            LOGGER.warning("Autosave timed out")
+            return
         
-        res = self.rcon.DSListGames()
+        if success:
+            self.launcher.notifications.send_event(EventType.AUTOSAVE, server_version=self.build_version)
+        else:
+            LOGGER.warning("Autosave failed")
+    
+    def external_save(self):
+        """ Saves the game on request from outside the launcher (SIGUSR1) and sends an EXTERNAL_SAVE event on success """
         
-        if not isinstance(res, dict):
-            return False
+        LOGGER.info("Saving game on external request...")
         
         try:
-            self.curr_game_list = GameList.from_dict(res)
-        except Exception as e:
-            LOGGER.error(f"Error while decoding received savegame list: {type(e)}: {str(e)}")
-            LOGGER.debug(f"Received response: {json.dumps(res)}")
+            success = self.save_game()
+        except ConsoleTimeoutError:
+            LOGGER.warning("Saving game timed out")
+            return
         
-        return True
+        if success:
+            self.launcher.notifications.send_event(EventType.EXTERNAL_SAVE, server_version=self.build_version)
+        else:
+            LOGGER.warning("Saving game failed")
     
-    def save_game(self, name=None):
+    def warn_players(self, seconds):
         """
-            Saves the game instantly.
+            Warns the players about an upcoming shutdown/restart and sends a SHUTDOWN_WARNING event
             
             Arguments:
-                - [name]: Filename to save the current savegame as
-            
-            Returns: A boolean indicating the success
+                - seconds: The remaining time in seconds until the shutdown/restart
         """
         
-        if not self.rcon.connected or (self.status != ServerStatus.RUNNING):
-            return False
+        action = "restarting" if self.shutdown_restart else "shutting down"
         
-        res = self.rcon.DSSaveGame(name)
+        self.launcher.notifications.send_event(EventType.SHUTDOWN_WARNING, action=action, seconds=seconds, server_version=self.build_version)
         
-        return res == True
+        try:
+            self.broadcast_message(f"The server is {action} in {seconds} seconds")
+        except NotImplementedError:
+            # Players can't be messaged yet, so the event is the only warning
+            pass
     
-    def load_game(self, save_name, force=False):
+    # Utility functions
+    
+    def get_resource_usage(self):
         """
-            Loads the savegame specified in {save_game}.
-            
-            Arguments:
-                - save_name: Name of the savegame to load
-                - [force]: Wether to send the command without checking the current saves list first
+            Gets the combined CPU and memory usage of the dedicated server process and its child processes.
+            The CPU usage is measured since the last call, so the first call for a process always reports 0%
             
-            Returns: A boolean indicating the success
+            Returns: A tuple containing the CPU usage in percent and the resident memory in bytes or None if not available
         """
         
-        if not self.rcon.connected or (self.status != ServerStatus.RUNNING):
-            return False
+        if (self.process is None) or (self.process.poll() is not None):
+            return None
         
-        # If force is false, check that {save_name} is actually in the save game list
-        if not force:
-            if self.curr_game_list is None:
-                raise TypeError("The current game list is None")
-            
-            found = False
-            
-            for game in self.curr_game_list.gameList:
-                if game.name == save_name:
-                    found = True
-            
-            if not found:
-                LOGGER.debug("Savegame not found")
-                return False
+        try:
+            root_process = psutil.Process(self.process.pid)
+            processes = [root_process] + root_process.children(recursive=True)
+        except psutil.Error:
+            return None
         
-        if not pathvalidate.is_valid_filename(save_name):
-            raise ValueError(f"'{save_name}' is not a valid savegame name")
+        cpu = 0.0
+        memory = 0
         
-        if (self.curr_game_list is not None) and (save_name == self.curr_game_list.activeSaveName):
-            LOGGER.debug("Save is already loaded, RCON command would fail, aborting")
-            return False
+        for proc in processes:
+            # Reuse process objects, such that the CPU usage can be calculated since the last call
+            proc = self.monitored_processes.setdefault(proc.pid, proc)
+            
+            try:
+                cpu += proc.cpu_percent(interval=None)
+                memory += proc.memory_info().rss
+            except psutil.Error:
+                # Process exited in the meantime
+                pass
         
-        res = self.rcon.DSLoadGame(save_name)
+        # Forget processes, that don't exist anymore
+        current_pids = [proc.pid for proc in processes]
+        self.monitored_processes = {pid: proc for pid, proc in self.monitored_processes.items() if pid in current_pids}
         
-        if not isinstance(res, dict):
-            LOGGER.debug(f"DSLoadGame result was not a dict: {str(res)}")
-            return False
+        return cpu, memory
+    
+    def check_resource_usage(self):
+        """ Logs the resource usage of the dedicated server and warns, if the memory usage exceeds the configured threshold """
         
-        if "status" not in res:
-            LOGGER.debug(f"DSLoadGame response didn't conmtain 'status' key: {str(res)}")
-            return False
+        usage = self.get_resource_usage()
         
-        if res["status"] == True:
-            return True
-        else:
-            LOGGER.debug(f"DSLoadGame failed: {res['_message']}")
-            return False
-    
-    def new_game(self, name=None):
-        """
-            Starts a new savegame.
-            
-            WARNING: Currently crashes the server running under wine
-            
-            Returns: A boolean indicating the success
-        """
+        if usage is None:
+            return
         
-        # Prevent people from using this
-        LOGGER.warning("Starting a new save game has been disabled currently due to the dedicated server crashing under wine while performing the operation")
-        LOGGER.warning("Please create new save games from inside the game")
-        return False
+        cpu, memory = usage
+        memory_mib = memory / (1024 * 1024)
         
-        if not self.rcon.connected or (self.status != ServerStatus.RUNNING):
-            return False
+        LOGGER.debug(f"Dedicated Server resource usage: CPU {round(cpu, 1)}%, Memory {round(memory_mib, 1)} MiB")
         
-        res1 = self.rcon.DSNewGame(name)
-        res2 = self.rcon.DSSaveGame(name)
+        threshold = self.launcher.config.MemoryWarningThreshold
         
-        return (res1 == True) and (res2 == True)
-    
-    # Utility functions
+        if threshold is None:
+            return
+        
+        # Only warn once when crossing the threshold
+        if memory_mib > threshold:
+            if not self.memory_warning_active:
+                LOGGER.warning(f"The Dedicated Server uses {round(memory_mib, 1)} MiB of memory, which exceeds the threshold of {threshold} MiB")
+                self.memory_warning_active = True
+        else:
+            self.memory_warning_active = False
     
     def quick_toggle_whitelist(self):
         """
@@ -1222,8 +2504,13 @@ class AstroDedicatedServer:
         
         wl_status = self.curr_server_stat.isEnforcingWhitelist
         
-        self.set_whitelist_enabled(not wl_status)
-        self.set_whitelist_enabled(wl_status)
+        try:
+            self.set_whitelist_enabled(not wl_status)
+            self.set_whitelist_enabled(wl_status)
+        except ConsoleTimeoutError as e:
+            LOGGER.warning(f"Timed out while toggling the whitelist: {str(e)}")
+            return False
+        
         self.reload_ds_config()
         
         return True
@@ -1297,25 +2584,54 @@ class AstroDedicatedServer:
         return []
     
     def check_ports_free(self):
-        
-        def is_port_in_use(port, tcp=True):
-            """ Checks if port is in use for TCP if {tcp} is true and for UDP if {tcp} is false """
-            conns = psutil.net_connections("inet")
-            matching = [c for c in conns 
-                        if c.type == (socket.SOCK_STREAM if tcp else socket.SOCK_DGRAM)
-                        and c.laddr[1] == port]
+        """
+            Checks that the server port (UDP) and the console port (TCP) are not used by a different process
             
-            return len(matching) > 0
-        
-        sp_free = not is_port_in_use(self.engine_config.Port)
-        cp_free = not is_port_in_use(self.ds_config.ConsolePort)
+            Returns: A boolean indicating, wether both ports are free
+        """
         
-        if not sp_free:
-            LOGGER.error(f"Server Port ({self.engine_config.Port}) already in use by different process")
-            return False
+        def get_port_user(port, tcp=True):
+            """
+                Checks if port is in use for TCP if {tcp} is true and for UDP if {tcp} is false by binding to it momentarily
+                
+                Returns: A tuple of a boolean indicating, wether the port is in use, and a description of the process using it, if known
+            """
+            
+            sock_type = socket.SOCK_STREAM if tcp else socket.SOCK_DGRAM
+            
+            try:
+                with socket.socket(socket.AF_INET, sock_type) as s:
+                    # Allow binding to TCP ports of connections, that are just being closed
+                    if tcp:
+                        s.setsockopt(socket.SOL_SOCKET, socket.SO_REUSEADDR, 1)
+                    
+                    s.bind(("", port))
+                
+                return False, None
+            except OSError:
+                pass
+            
+            # Try to find the process using the port, which might not be possible without sufficient permissions
+            try:
+                for c in psutil.net_connections("inet"):
+                    if (c.type == sock_type) and c.laddr and (c.laddr[1] == port) and (c.pid is not None):
+                        return True, f"{psutil.Process(c.pid).name()} (PID {c.pid})"
+            except (psutil.AccessDenied, psutil.NoSuchProcess):
+                pass
+            
+            return True, None
         
-        if not cp_free:
-            LOGGER.error(f"Console Port ({self.ds_config.ConsolePort}) already in use by different process")
-            return False
+        for port_name, port, tcp in [("Server Port", self.engine_config.Port, False), ("Console Port", self.ds_config.ConsolePort, True)]:
+            in_use, user = get_port_user(port, tcp)
+            
+            if in_use:
+                protocol = "TCP" if tcp else "UDP"
+                
+                if user:
+                    LOGGER.error(f"{port_name} ({port}/{protocol}) already in use by process {user}")
+                else:
+                    LOGGER.error(f"{port_name} ({port}/{protocol}) already in use by different process")
+                
+                return False
         
-        return True
\ No newline at end of file
+        return True
@@ -8,11 +8,47 @@ import urllib.error
 from urllib import request
 import ssl
 import time
+import threading
 from utils.net import get_request, post_request
 import logging
 
 LOGGER = logging.getLogger("Playfab")
 
+#
+#   Throttling of requests to the Playfab API
+#
+
+_request_interval = 0
+_last_request_time = None
+_request_lock = threading.Lock()
+
+def set_request_interval(interval):
+    """
+        Sets the minimum time in seconds between two requests to the Playfab API, such that bursts of requests don't get the server rate limited
+        
+        Arguments:
+            - interval: The minimum time between requests. 0 disables throttling
+    """
+    
+    global _request_interval
+    _request_interval = interval
+
+def _throttle():
+    """ Waits until the minimum time since the last request to the Playfab API has passed """
+    
+    global _last_request_time
+    
+    # Requests from multiple threads are sent one after another
+    with _request_lock:
+        if _last_request_time is not None:
+            wait_time = _request_interval - (time.monotonic() - _last_request_time)
+            
+            if wait_time > 0:
+                LOGGER.debug(f"Waiting {round(wait_time, 2)} seconds before next Playfab API request")
+                time.sleep(wait_time)
+        
+        _last_request_time = time.monotonic()
+
 #
 #   Methods for interacting with the Playfab Astroneer API
 #
@@ -38,6 +74,8 @@ def check_api_health():
     
     url = "https://5ea1.playfabapi.com/"
     
+    _throttle()
+    
     try:
         resp = json.load(get_request(url))
         
@@ -65,16 +103,16 @@ def generate_XAuth(serverGUID):
         "TitleId": "5EA1"
     }
     
+    _throttle()
     response = post_request(url, headers=base_headers, jsonData=requestObject)
     LOGGER.debug(f"Response for first XAuth request: {str(response)}")
     response = json.load(response)
     
     # If account doesn't exist, create new one
     if (response["code"] == 400) and (response["error"] == "AccountNotFound"):
-        time.sleep(0.2)
-        
         requestObject["CreateAccount"] = True
         
+        _throttle()
         response = post_request(url, headers=base_headers, jsonData=requestObject)
         LOGGER.debug(f"Response for second XAuth request: {str(response)}")
         response = json.load(response)
@@ -107,6 +145,8 @@ def get_server(IPPortCombo, XAuth):
     headers = base_headers.copy()
     headers["X-Authorization"] = XAuth
     
+    _throttle()
+    
     try:
         response = json.load(post_request(url, headers=headers, jsonData=requestObject))
         
@@ -140,6 +180,8 @@ def deregister_server(lobbyID, XAuth):
     headers = base_headers.copy()
     headers["X-Authorization"] = XAuth
     
+    _throttle()
+    
     try:
         response = json.load(post_request(url, headers=headers, jsonData=requestObject))
         
@@ -188,6 +230,8 @@ def heartbeat_server(serverData, XAuth, dataToChange=None):
     if dataToChange is not None:
         requestObject['FunctionParameter'].update(dataToChange)
     
+    _throttle()
+    
     try:
         response = json.load(post_request(url, headers=headers, jsonData=requestObject))
         
@@ -15,28 +15,155 @@ class PlayerCategory(Enum):
     PENDING = "Pending"
     OWNER = "Owner"
 
+class ConsoleError(Exception):
+    """
+        Base class of the errors, that can occur while communicating with the Dedicated Server over the console port.
+        {hint} describes what can be done about the error, if known
+    """
+    
+    hint = None
+    
+    def __init__(self, message):
+        self.message = message
+        super().__init__(self.message)
+    
+    def describe(self):
+        """ Returns the message of the error followed by the hint, if there is one """
+        
+        return f"{self.message}. {self.hint}" if self.hint else self.message
+
+class ConsoleConnectionRefusedError(ConsoleError):
+    hint = "Is the Dedicated Server running and the console port correct?"
+    
+    def __init__(self, message="The connection to the console port was refused"):
+        super().__init__(message)
+
+class ConsoleTimeoutError(ConsoleError):
+    hint = "Is the Dedicated Server overloaded or the network connection slow?"
+    
+    def __init__(self, message="The Dedicated Server didn't answer in time"):
+        super().__init__(message)
+
+class ConsoleAuthError(ConsoleError):
+    hint = "Check the console port and password"
+    
+    def __init__(self, message="The Dedicated Server closed the connection without answering after authenticating"):
+        super().__init__(message)
+
+class ConsoleProtocolError(ConsoleError):
+    """ The Dedicated Server answered with data, that could not be understood """
+    
+    hint = "Check that the console port belongs to an Astroneer Dedicated Server"
+
+class ConsoleRejectedError(ConsoleError):
+    """ The Dedicated Server refused to handle the request, e.g. by closing the connection """
+    pass
+
+class ConsoleIOError(ConsoleError):
+    """ Sending or receiving data failed because of an error of the underlying socket, which is kept in {error} """
+    
+    def __init__(self, error):
+        self.error = error
+        super().__init__(str(error))
+
+# Console commands known to the launcher, which are sent with the same name by default
+CONSOLE_COMMANDS = ["DSSetPlayerCategoryForPlayerName", "DSSetDenyUnlisted", "DSKickPlayerGuid", "DSServerStatistics", "DSListPlayers", "DSLoadGame", "DSSaveGame", "DSNewGame", "DSServerShutdown", "DSListGames"]
+
+def get_command_names(overrides=None):
+    """
+        Determines the names of the console commands to use for a Dedicated Server
+        
+        Arguments:
+            - [overrides]: Dictionary of command names, that overrule the default ones
+        
+        Returns: A dictionary of the names to send, by command
+    """
+    
+    command_names = {cmd: cmd for cmd in CONSOLE_COMMANDS}
+    
+    if overrides:
+        command_names.update(overrides)
+    
+    return command_names
+
+# Number of unanswered TCP keep-alive packets after which the connection is considered dead
+KEEPALIVE_PROBES = 3
+
 #TODO: Maybe Thread safety using mutex lock
 
 class AstroRCON():
     """
         Class for communicating with an Astroneer Dedicated Server using RCON over TCP.
+        Command methods return None, if they failed, and keep the ConsoleError describing why in {last_error}.
         Arguments:
             - port: The RCON port of the Astroneer Server
             - password: The password used to authenticate the RCON connection to the dedicated server
             - [ip]: The IP where the dedicated server is located (Default: Only local)
+            - [timeout]: Time in seconds to wait for the dedicated server to answer a command (Default: Wait indefinitely)
+            - [command_names]: Dictionary of the names to send for the console commands, as returned by get_command_names (Default: The default names)
+            - [keepalive]: Idle time in seconds after which TCP keep-alive packets are sent to prevent the connection from being dropped (Default: Disabled)
     """
     
-    def __init__(self, port, password=None, ip="127.0.0.1"):
+    def __init__(self, port, password=None, ip="127.0.0.1", timeout=None, command_names=None, keepalive=None):
         self.port = port
         self.password = password
         self.ip = ip
+        self.timeout = timeout
+        self.command_names = command_names if command_names is not None else get_command_names()
+        self.keepalive = keepalive
         
         self.socket = None
         self.connected = False
+        
+        # Wether the Dedicated Server has answered since connecting, which shows that the password was accepted
+        self.answered = False
+        
+        # ConsoleError describing why the last operation failed, None if it succeeded
+        self.last_error = None
     
     def _createSocket(self):
         """ Creates a new TCP IPv4 socket """
         self.socket = socket.socket(socket.AF_INET, socket.SOCK_STREAM)
+        self._applyKeepalive()
+    
+    def set_timeout(self, timeout):
+        """ Sets the time in seconds to wait for the dedicated server to answer a command """
+        self.timeout = timeout
+        
+        if self.socket is not None:
+            self.socket.settimeout(timeout)
+    
+    def set_keepalive(self, keepalive):
+        """ Sets the idle time in seconds after which TCP keep-alive packets are sent. None or 0 disables keep-alive """
+        self.keepalive = keepalive
+        
+        if self.socket is not None:
+            self._applyKeepalive()
+    
+    def _applyKeepalive(self):
+        """ Enables or disables TCP keep-alive on the socket according to {self.keepalive} """
+        
+        if not self.keepalive:
+            self.socket.setsockopt(socket.SOL_SOCKET, socket.SO_KEEPALIVE, 0)
+            return
+        
+        self.socket.setsockopt(socket.SOL_SOCKET, socket.SO_KEEPALIVE, 1)
+        
+        # The timing options are not available on every platform
+        interval = max(int(self.keepalive), 1)
+        
+        if hasattr(socket, "TCP_KEEPIDLE"):
+            self.socket.setsockopt(socket.IPPROTO_TCP, socket.TCP_KEEPIDLE, interval)
+        
+        if hasattr(socket, "TCP_KEEPINTVL"):
+            self.socket.setsockopt(socket.IPPROTO_TCP, socket.TCP_KEEPINTVL, interval)
+        
+        if hasattr(socket, "TCP_KEEPCNT"):
+            self.socket.setsockopt(socket.IPPROTO_TCP, socket.TCP_KEEPCNT, KEEPALIVE_PROBES)
+    
+    def set_command_names(self, command_names):
+        """ Sets the names to send for the console commands, as returned by get_command_names """
+        self.command_names = command_names
     
     def disconnect(self):
         self.connected = False
@@ -47,8 +174,15 @@ class AstroRCON():
         except:
             pass
     
-    def connect(self):
-        """ Tries to connect to the dedciated server RCON port """
+    def connect(self, timeout=None):
+        """
+            Tries to connect to the dedciated server RCON port
+            
+            Arguments:
+                - [timeout]: Time in seconds to wait for the connection to be established (Default: Wait indefinitely)
+            
+            Raises: ConsoleConnectionRefusedError, ConsoleTimeoutError or ConsoleIOError, if the connection could not be established
+        """
         # If no socket exists, create new one
         if (self.socket is None):
             self._createSocket()
@@ -58,14 +192,28 @@ class AstroRCON():
             return
         
         # Connect to Astroneer Server RCON port
-        self.socket.connect((self.ip, self.port))
-        
-        if not (self.password is None):
+        try:
+            self.socket.settimeout(timeout)
+            self.socket.connect((self.ip, self.port))
+            self.socket.settimeout(self.timeout)
             
-            # Send RCON password to authenticate connection
-            self.socket.sendall(f"{self.password}\n".encode())
+            if not (self.password is None):
+                
+                # Send RCON password to authenticate connection
+                self.socket.sendall(f"{self.password}\n".encode())
+        except ConnectionRefusedError:
+            self.last_error = ConsoleConnectionRefusedError()
+        except socket.timeout:
+            self.last_error = ConsoleTimeoutError(f"The connection to the console port couldn't be established within {timeout} seconds")
+        except OSError as e:
+            self.last_error = ConsoleIOError(e)
+        else:
+            self.last_error = None
+            self.answered = False
+            self.connected = True
+            return
         
-        self.connected = True
+        raise self.last_error
     
     def ensureConnection(self):
         """ Try to connect if not connected and check the connection. Returns the connected status """
@@ -115,10 +263,28 @@ class AstroRCON():
                 if len(chunk) < BUFF_SIZE:
                     break
             
+            # Receiving no data at all means, that the Dedicated Server closed the connection
+            if len(data_buf) == 0:
+                if (self.password is not None) and not self.answered:
+                    self.last_error = ConsoleAuthError()
+                else:
+                    self.last_error = ConsoleRejectedError("The Dedicated Server closed the connection")
+                
+                self.disconnect()
+                return None
+            
+            self.answered = True
+            
             # We've hopefully received the full data block, so return it
             return data_buf
-        except:
+        except socket.timeout:
+            # A late answer would be received as the answer to the next command, so drop the connection
+            self.disconnect()
+            self.last_error = ConsoleTimeoutError(f"The Dedicated Server didn't answer within {self.timeout} seconds")
+            raise self.last_error
+        except OSError as e:
             # Error happened during receiving, so return nothing
+            self.last_error = ConsoleIOError(e)
             return None
     
     @staticmethod
@@ -136,7 +302,7 @@ class AstroRCON():
             # Couldn't parse JSON, return raw data
             return raw_data
     
-    def _sendreceive(self, data, recvdata=True):
+    def _sendreceive(self, data, recvdata=True, expect_json=True):
         """
             Send data to socket and possibly receive response data if {recvdata} is True.
             Enters the disconnected state, if the sending of data produces an arror.
@@ -144,23 +310,33 @@ class AstroRCON():
             Arguments:
                 - data: The data to send to the socket
                 - [recvdata]: Wether to expect and receive response data
+                - [expect_json]: Wether the response data has to be JSON. If not, a raw answer isn't treated as an error
             
             Returns:
                 - response data, if {recvdata} is True and data was successfully sent ad response data successfully received
                 - True, if {recvdata} is false and the sending was successful
                 - None, if we're not connected, the data is empty or an error occurred wile sending or receiving
+            
+            Raises: ConsoleTimeoutError, if {recvdata} is True and no response was received in time
         """
         
         # If we're not connected or data is empty, immediately return
-        if (not self.connected) or (len(data) == 0):
+        if not self.connected:
+            self.last_error = ConsoleIOError(OSError("Not connected to the console port"))
             return None
+        
+        if len(data) == 0:
+            return None
+        
+        self.last_error = None
 
         # Try to send data
         try:
             self.socket.sendall(data)
-        except:
+        except OSError as e:
             # If we get an exception, assume the connection was broken and enter disconnected state
             self.disconnect()
+            self.last_error = ConsoleIOError(e)
             return None
         
         # If we don't want to receive data following the sending, we're finished
@@ -170,10 +346,18 @@ class AstroRCON():
         # Receive answer data
         try:
             response = self._recvMessage()
-            return response
-        except:
+        except ConsoleTimeoutError:
+            raise
+        except Exception as e:
+            self.last_error = ConsoleProtocolError(f"Could not process the answer of the Dedicated Server: {str(e)}")
             return None
         
+        # Answers, that aren't JSON, are returned as raw data
+        if expect_json and isinstance(response, bytes):
+            self.last_error = ConsoleProtocolError(f"The Dedicated Server sent an answer, that isn't JSON: {response[:64]}")
+        
+        return response
+    
     #
     #   Functions to send Commands to the server
     #
@@ -194,7 +378,7 @@ class AstroRCON():
         # Escape quotation marks in player name
         escapedName = playerName.replace('"', '\\"')
         
-        return self._sendreceive(f'DSSetPlayerCategoryForPlayerName "{escapedName}" {category.value}\n'.encode(), True)
+        return self._sendreceive(f'{self.command_names["DSSetPlayerCategoryForPlayerName"]} "{escapedName}" {category.value}\n'.encode(), True)
     
     def DSSetDenyUnlisted(self, state):
         """
@@ -208,7 +392,7 @@ class AstroRCON():
             Returns: Received Data
         """
         
-        return self._sendreceive(f'DSSetDenyUnlisted {str(state).lower()}\n'.encode(), True)
+        return self._sendreceive(f'{self.command_names["DSSetDenyUnlisted"]} {str(state).lower()}\n'.encode(), True, expect_json=False)
     
     def DSKickPlayerGuid(self, playerGuid):
         """
@@ -222,7 +406,7 @@ class AstroRCON():
             Returns: Received Data
         """
         
-        return self._sendreceive(f'DSKickPlayerGuid {str(playerGuid)}\n'.encode(), True)
+        return self._sendreceive(f'{self.command_names["DSKickPlayerGuid"]} {str(playerGuid)}\n'.encode(), True, expect_json=False)
     
     def DSServerStatistics(self):
         """
@@ -233,7 +417,7 @@ class AstroRCON():
             Returns: Received Data
         """
         
-        return self._sendreceive(f'DSServerStatistics\n'.encode(), True)
+        return self._sendreceive(f'{self.command_names["DSServerStatistics"]}\n'.encode(), True)
     
     def DSListPlayers(self):
         """
@@ -244,7 +428,7 @@ class AstroRCON():
             Returns: Received Data
         """
         
-        return self._sendreceive(f'DSListPlayers\n'.encode(), True)
+        return self._sendreceive(f'{self.command_names["DSListPlayers"]}\n'.encode(), True)
     
     def DSLoadGame(self, saveName):
         """
@@ -255,7 +439,7 @@ class AstroRCON():
             Returns: True if successfully sent command
         """
         
-        return self._sendreceive(f'DSLoadGame {saveName}\n'.encode(), True)
+        return self._sendreceive(f'{self.command_names["DSLoadGame"]} {saveName}\n'.encode(), True)
     
     def DSSaveGame(self, name=None):
         """
@@ -270,9 +454,9 @@ class AstroRCON():
         """
         
         if (name is None):
-            response = self._sendreceive(f'DSSaveGame\n'.encode(), False)
+            response = self._sendreceive(f'{self.command_names["DSSaveGame"]}\n'.encode(), False)
         else:
-            response = self._sendreceive(f'DSSaveGame {name}\n'.encode(), False)
+            response = self._sendreceive(f'{self.command_names["DSSaveGame"]} {name}\n'.encode(), False)
         
         if (response is None):
             return response
@@ -293,9 +477,18 @@ class AstroRCON():
         """
         
         if save_name is None:
-            return self._sendreceive(f'DSNewGame\n'.encode(), False)
+            return self._sendreceive(f'{self.command_names["DSNewGame"]}\n'.encode(), False)
         else:
-            return self._sendreceive(f'DSNewGame {save_name}\n'.encode(), False)
+            return self._sendreceive(f'{self.command_names["DSNewGame"]} {save_name}\n'.encode(), False)
+    
+    def sendRaw(self, command):
+        """
+            Sends the given {command} to the Dedicated Server without checking it.
+            
+            Returns: Received Data
+        """
+        
+        return self._sendreceive(f'{command}\n'.encode(), True, expect_json=False)
     
     def DSServerShutdown(self):
         """
@@ -306,7 +499,7 @@ class AstroRCON():
             Returns: True if successfully sent command
         """
         
-        return self._sendreceive(f'DSServerShutdown\n'.encode(), False)
+        return self._sendreceive(f'{self.command_names["DSServerShutdown"]}\n'.encode(), False)
     
     def DSListGames(self):
         """
@@ -317,4 +510,4 @@ class AstroRCON():
             Returns: Received Data
         """
         
-        return self._sendreceive(f'DSListGames\n'.encode(), True)
+        return self._sendreceive(f'{self.command_names["DSListGames"]}\n'.encode(), True)
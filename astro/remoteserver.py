@@ -0,0 +1,164 @@
+import logging
+import time
+import traceback
+from astro.rcon import AstroRCON, ConsoleError, ConsoleTimeoutError, get_command_names
+from astro.dedicatedserver import DedicatedServerConsole, ServerStatus
+from utils.interface import EventType, ConsoleParser
+
+LOGGER = logging.getLogger("RemoteServer")
+CMD_LOGGER = logging.getLogger("Command")
+
+# Time in seconds to wait for the connection to the console port to be established
+CONNECT_TIMEOUT = 10
+
+# Number of attempts to re-establish a lost connection and the delay in seconds before the first one, which doubles with every attempt
+RECONNECT_ATTEMPTS = 5
+RECONNECT_DELAY = 1
+
+class RemoteDedicatedServer(DedicatedServerConsole):
+    """
+        Class for administering an already running Astroneer Dedicated Server using its console port over the network
+        
+        Arguments:
+            - launcher: The launcher object
+            - host: The host the Dedicated Server is running on
+            - port: The console port of the Dedicated Server
+            - [password]: The console password of the Dedicated Server
+    """
+    
+    def __init__(self, launcher, host, port, password=None):
+        super().__init__(launcher, AstroRCON(port, password, ip=host, timeout=launcher.config.ConsoleCommandTimeout, keepalive=launcher.config.ConsoleKeepAlive, command_names=get_command_names(launcher.config.ConsoleCommandNames)))
+        
+        self.host = host
+        self.port = port
+    
+    def connect(self):
+        """
+            Connects to the console port of the remote Dedicated Server and requests initial information about it
+            
+            Returns: A boolean indicating the success
+        """
+        
+        LOGGER.info(f"Connecting to Dedicated Server at {self.host}:{self.port}...")
+        
+        try:
+            self.rcon.connect(timeout=CONNECT_TIMEOUT)
+        except ConsoleError as e:
+            LOGGER.error(f"Could not connect to {self.host}:{self.port}: {e.describe()}")
+            self.rcon.disconnect()
+            return False
+        
+        self.status = ServerStatus.RUNNING
+        
+        if self.update_server_info():
+            self.last_server_status = time.time()
+        else:
+            # With a wrong password, the Dedicated Server closes the connection without answering, which is reported as ConsoleAuthError
+            error = self.rcon.last_error
+            LOGGER.error(f"Could not get information from the Dedicated Server: {error.describe() if error else 'Unexpected answer'}")
+            self.disconnect()
+            return False
+        
+        self.build_version = self.curr_server_stat.build
+        
+        LOGGER.info(f"Connected to Dedicated Server (v{self.build_version})")
+        
+        return True
+    
+    def reconnect(self):
+        """
+            Tries to re-establish a lost connection to the remote Dedicated Server with increasing delay between attempts.
+            The server information is updated after reconnecting.
+            
+            Returns: A boolean indicating the success
+        """
+        
+        self.rcon.disconnect()
+        
+        for attempt in range(1, RECONNECT_ATTEMPTS + 1):
+            delay = RECONNECT_DELAY * (2 ** (attempt - 1))
+            
+            LOGGER.warning(f"Connection to the Dedicated Server lost, reconnecting in {delay} seconds (Attempt {attempt}/{RECONNECT_ATTEMPTS})...")
+            time.sleep(delay)
+            
+            try:
+                self.rcon.connect(timeout=CONNECT_TIMEOUT)
+            except ConsoleError as e:
+                LOGGER.debug(f"Reconnecting failed: {str(e)}")
+                self.rcon.disconnect()
+                continue
+            
+            if self.update_server_info():
+                self.last_server_status = time.time()
+                LOGGER.info(f"Reconnected to Dedicated Server at {self.host}:{self.port}")
+                return True
+            
+            self.rcon.disconnect()
+        
+        LOGGER.error(f"Could not reconnect to the Dedicated Server after {RECONNECT_ATTEMPTS} attempts")
+        return False
+    
+    def disconnect(self):
+        """ Closes the connection to the remote Dedicated Server """
+        
+        self.rcon.disconnect()
+        self.status = ServerStatus.OFF
+        
+        self.curr_server_stat = None
+        self.curr_player_list = None
+        self.curr_game_list = None
+    
+    def console_loop(self):
+        """
+            Loop to run while connected to the remote Dedicated Server that executes commands and updates the server information
+        """
+        
+        while True:
+            # Exit loop, if disconnected
+            if self.status == ServerStatus.OFF:
+                break
+            
+            update_server_data = False
+            
+            if (time.time() - self.last_server_status) >= self.launcher.config.ServerStatusInterval:
+                update_server_data = True
+                
+                if self.update_server_info():
+                    self.last_server_status = time.time()
+                elif not self.reconnect():
+                    # A failed update usually means, that the connection was dropped
+                    self.disconnect()
+                    break
+            
+            # Handle console commands in queue
+            while not self.launcher.cmd_queue.empty():
+                args = self.launcher.cmd_queue.get()
+                
+                try:
+                    if args["cmd"] == ConsoleParser.Command.DISCONNECT:
+                        LOGGER.info(f"Disconnecting from {self.host}:{self.port}...")
+                        self.disconnect()
+                        break
+                    
+                    if not self.execute_command(args):
+                        CMD_LOGGER.warning("This command is not supported for remote Dedicated Servers")
+                    
+                    # If the connection was dropped while executing the command, try to get it back for the following commands
+                    if not self.rcon.connected and (self.rcon.last_error is not None):
+                        CMD_LOGGER.error(f"The connection was lost while executing the command: {self.rcon.last_error.describe()}")
+                    
+                    if not self.rcon.connected and not self.reconnect():
+                        self.disconnect()
+                        break
+                    
+                    # Send notification event after executing command
+                    self.launcher.notifications.send_event(EventType.COMMAND, command=args["cmdline"], server_version=self.build_version)
+                except ConsoleTimeoutError as e:
+                    CMD_LOGGER.error(f"Command timed out: {str(e)}")
+                except Exception as e:
+                    CMD_LOGGER.error(f"Error occured while executing command: {str(e)}")
+                    CMD_LOGGER.error(traceback.format_exc())
+            
+            # If we didn't do an update, wait a short while. This reduces the CPU usage of the launcher
+            if not update_server_data:
+                time.sleep(0.005)
@@ -11,13 +11,25 @@ from datetime import datetime, timezone
 import argparse
 import re
 import subprocess
+import gzip
+import unicodedata
+from string import Formatter
+from collections import deque
+import shutil
 from alive_progress.animations.spinners import frame_spinner_factory
-from utils.misc import LAUNCHER_VERSION
+from utils.misc import LAUNCHER_VERSION, redact_url
 import utils.net as net
 import json
 from requests.utils import requote_uri
 import urllib.parse as urlparse
+import html
+import uuid
+import tempfile
+import smtplib
+import ssl
+from email.message import EmailMessage
 from utils.termutils import ANSI
+from pansi import ansi
 
 # Hack to detect wether UTF-8 symbols in the progress bar will cause problems
 def _check_fancy_symbol_support():
@@ -196,6 +208,9 @@ class SubParserEnumStoreAction(argparse._SubParsersAction):
         self._enum = type
         self._enum_choices = [e.value for e in self._enum]
         
+        # Maps aliases to the enum value of the actual subcommand
+        self._aliases = {}
+        
         super().__init__(option_strings=option_strings, prog=prog, parser_class=parser_class, dest=dest, required=required, help=help, metavar=metavar)
     
     def add_parser(self, name, **kwargs):
@@ -206,6 +221,9 @@ class SubParserEnumStoreAction(argparse._SubParsersAction):
         if not (name in self._enum_choices):
             raise ValueError("name has to be a member of given enum")
         
+        for alias in kwargs.get("aliases", []):
+            self._aliases[alias] = name
+        
         return super().add_parser(name, **kwargs)
     
     def __call__(self, parser, namespace, values, option_string=None):
@@ -219,6 +237,9 @@ class SubParserEnumStoreAction(argparse._SubParsersAction):
         if self.dest is not argparse.SUPPRESS:
             val = getattr(namespace, self.dest, None)
             
+            # Resolve aliases to the name of the actual subcommand
+            val = self._aliases.get(val, val)
+            
             try:
                 val = self._enum(val)
             except ValueError:
@@ -239,10 +260,21 @@ class ConsoleParser:
         RESTART = "restart"
         INFO = "info"
         KICK = "kick"
+        KICK_ALL = "kick-all"
         WHITELIST = "whitelist"
         LIST = "list"
         SAVEGAME = "savegame"
         PLAYER = "player"
+        BAN = "ban"
+        UNBAN = "unban"
+        BROADCAST = "broadcast"
+        RAW = "raw"
+        SERVERCONFIG = "serverconfig"
+        MAXPLAYERS = "maxplayers"
+        RELOAD = "reload"
+        EVENTS = "events"
+        UPTIME = "uptime"
+        DISCONNECT = "disconnect"
     
     class WhitelistSubcommand(Enum):
         ENABLE = "enable"
@@ -267,13 +299,25 @@ class ConsoleParser:
         SET = "set"
         GET = "get"
         
+    class ServerConfigSubcommand(Enum):
+        GET = "get"
+        SET_NAME = "set-name"
+        SET_PASSWORD = "set-password"
+    
     class PlayerCategory(Enum):
         UNLISTED = "unlisted"
         BLACKLISTED = "blacklisted"
         WHITELISTED = "whitelisted"
         ADMIN = "admin"
     
-    def __init__(self):
+    def __init__(self, remote=False):
+        """
+            Arguments:
+                - [remote]: Wether the commands are executed on a remote Dedicated Server.
+                    Commands, that require a local server process are left out and the 'disconnect' command is added
+        """
+        
+        self.remote = remote
         self.parser = ArgumentParser(prog="", add_help=False, exit_on_error=False)
         
         subparser_section = self.parser.add_subparsers(parser_class=ArgumentParser, title="Command", description=None, dest="cmd", type=ConsoleParser.Command, action=SubParserEnumStoreAction, required=True)
@@ -287,19 +331,52 @@ class ConsoleParser:
         self.subparsers["help"].add_argument("command", type=str, nargs="?", help="The command to get help for")
         self.subparsers["help"].add_argument("subcommand", type=str, nargs="?", help="The subcommand to get help for")
         
-        ## 'shutdown' command
-        self.subparsers["shutdown"] = subparser_section.add_parser(ConsoleParser.Command.SHUTDOWN, help="Shuts down the Dedicated Server", description="Shuts down the Dedicated Server", add_help=False, exit_on_error=False)
-        
-        ## 'shutdown' command
-        self.subparsers["restart"] = subparser_section.add_parser(ConsoleParser.Command.RESTART, help="Restarts the Dedicated Server", description="Restarts the Dedicated Server", add_help=False, exit_on_error=False)
+        if not remote:
+            ## 'shutdown' command
+            self.subparsers["shutdown"] = subparser_section.add_parser(ConsoleParser.Command.SHUTDOWN, help="Saves the game and shuts down the Dedicated Server", description="Saves the game and shuts down the Dedicated Server. If a countdown is given, players are warned in intervals before shutting down", add_help=False, exit_on_error=False)
+            self.subparsers["shutdown"].add_argument("countdown", type=int, nargs="?", help="The time in seconds to wait before shutting down")
+            
+            ## 'restart' command
+            self.subparsers["restart"] = subparser_section.add_parser(ConsoleParser.Command.RESTART, help="Saves the game and restarts the Dedicated Server", description="Saves the game and restarts the Dedicated Server without exiting the launcher. If a countdown is given, players are warned in intervals before restarting", add_help=False, exit_on_error=False)
+            self.subparsers["restart"].add_argument("countdown", type=int, nargs="?", help="The time in seconds to wait before restarting")
+            
+            ## 'serverconfig' command
+            self.subparsers["serverconfig"] = subparser_section.add_parser(ConsoleParser.Command.SERVERCONFIG, help="Shows and changes the name and password of the Dedicated Server", description="Shows and changes the name and password of the Dedicated Server. Changes are saved to AstroServerSettings.ini and take effect after a restart", add_help=False, exit_on_error=False)
+            serverconfig_section = self.subparsers["serverconfig"].add_subparsers(parser_class=ArgumentParser, title="Sub-Command", description=None, dest="subcmd", type=ConsoleParser.ServerConfigSubcommand, action=SubParserEnumStoreAction, required=True)
+            
+            self.subparsers["serverconfig.get"] = serverconfig_section.add_parser(ConsoleParser.ServerConfigSubcommand.GET, add_help=False, exit_on_error=False, help="Shows the current server name and wether a password is set", description="Shows the current server name and wether a password is set")
+            
+            self.subparsers["serverconfig.set-name"] = serverconfig_section.add_parser(ConsoleParser.ServerConfigSubcommand.SET_NAME, add_help=False, exit_on_error=False, help="Sets the name of the server", description="Sets the name of the server")
+            self.subparsers["serverconfig.set-name"].add_argument("name", type=str, help="The new name of the server (Use quotes for multiple words)")
+            
+            self.subparsers["serverconfig.set-password"] = serverconfig_section.add_parser(ConsoleParser.ServerConfigSubcommand.SET_PASSWORD, add_help=False, exit_on_error=False, help="Sets the password of the server. Removes the password, if none is given", description="Sets the password of the server. Removes the password, if none is given")
+            self.subparsers["serverconfig.set-password"].add_argument("password", type=str, nargs="?", help="The new password of the server")
+            
+            ## 'reload' command
+            self.subparsers["reload"] = subparser_section.add_parser(ConsoleParser.Command.RELOAD, help="Applies the whitelist and player categories from AstroServerSettings.ini to the running Dedicated Server", description="Applies the whitelist setting and player categories from AstroServerSettings.ini to the running Dedicated Server, e.g. after editing the file directly, without restarting it", add_help=False, exit_on_error=False)
+            
+            ## 'uptime' command
+            self.subparsers["uptime"] = subparser_section.add_parser(ConsoleParser.Command.UPTIME, help="Shows how long the Dedicated Server has been running", description="Shows how long the Dedicated Server has been running since it was first started by the launcher and since the last restart, as well as the number of restarts", add_help=False, exit_on_error=False)
+            
+            ## 'maxplayers' command
+            self.subparsers["maxplayers"] = subparser_section.add_parser(ConsoleParser.Command.MAXPLAYERS, help="Shows or sets the maximum number of players on the server", description="Shows the maximum number of players on the server or sets it, if a count is given. Changes are saved to AstroServerSettings.ini and take effect after a restart", add_help=False, exit_on_error=False)
+            self.subparsers["maxplayers"].add_argument("count", type=int, nargs="?", help="The new maximum number of players")
+        else:
+            ## 'disconnect' command
+            self.subparsers["disconnect"] = subparser_section.add_parser(ConsoleParser.Command.DISCONNECT, help="Disconnects from the remote Dedicated Server and quits", description="Disconnects from the remote Dedicated Server and quits", add_help=False, exit_on_error=False)
         
         ## 'info' command
         self.subparsers["info"] = subparser_section.add_parser(ConsoleParser.Command.INFO, help="Gives information about the running Dedicated Server", description="Gives information about the running Dedicated Server", add_help=False, exit_on_error=False)
+        self.subparsers["info"].add_argument("--json", action="store_true", dest="json", default=False, help="Output the result as JSON")
         
         ## 'kick' command
         self.subparsers["kick"] = subparser_section.add_parser(ConsoleParser.Command.KICK, help="Kicks a player from the server", description="Kicks a player from the server", add_help=False, exit_on_error=False)
         self.subparsers["kick"].add_argument("player", type=str, help="The GUID or name of the player to kick")
         
+        ## 'kick-all' command
+        self.subparsers["kick-all"] = subparser_section.add_parser(ConsoleParser.Command.KICK_ALL, help="Kicks all online players from the server", description="Kicks all online players from the server, e.g. before maintenance. The reason is only included in the sent event, as players can't be messaged", add_help=False, exit_on_error=False)
+        self.subparsers["kick-all"].add_argument("reason", type=str, nargs="?", help="The reason for kicking the players (Use quotes for multiple words)")
+        
         ## 'whitelist' command
         self.subparsers["whitelist"] = subparser_section.add_parser(ConsoleParser.Command.WHITELIST, help="Manages/Queries the whitelist status", description="Manages/Queries whitelist status", add_help=False, exit_on_error=False)
         whitelist_section = self.subparsers["whitelist"].add_subparsers(parser_class=ArgumentParser, title="Sub-Command", description=None, dest="subcmd", type=ConsoleParser.WhitelistSubcommand, action=SubParserEnumStoreAction, required=True)
@@ -311,6 +388,8 @@ class ConsoleParser:
         ## 'list' command
         self.subparsers["list"] = subparser_section.add_parser(ConsoleParser.Command.LIST, help="List players. Filter by provided category, if specified", description="List players. Filter by provided category, if specified", add_help=False, exit_on_error=False, formatter_class=argparse.ArgumentDefaultsHelpFormatter)
         self.subparsers["list"].add_argument("category", type=ConsoleParser.ListCategory, action=EnumStoreAction, default=ConsoleParser.ListCategory.ALL, nargs="?", help="The category to filter the output list on")
+        self.subparsers["list"].add_argument("-a", "--all", action="store_true", dest="include_offline", default=False, help="Also list known players, that are not connected right now")
+        self.subparsers["list"].add_argument("--json", action="store_true", dest="json", default=False, help="Output the result as JSON")
         
         ## 'savegame' command
         self.subparsers["savegame"] = subparser_section.add_parser(ConsoleParser.Command.SAVEGAME, help="Manages savegames", description="Manages savegames", add_help=False, exit_on_error=False)
@@ -326,6 +405,7 @@ class ConsoleParser:
         self.subparsers["savegame.new"].add_argument("save_name", type=str, nargs="?",help="The name of the new save to create")
         
         self.subparsers["savegame.list"] = savegame_section.add_parser(ConsoleParser.SaveGameSubcommand.LIST, add_help=False, exit_on_error=False, help="List all the available savegames and marks the active one", description="List all the available savegames and marks the active one")
+        self.subparsers["savegame.list"].add_argument("--json", action="store_true", dest="json", default=False, help="Output the result as JSON")
 
         ## 'player' command
         self.subparsers["player"] = subparser_section.add_parser(ConsoleParser.Command.PLAYER, help="Manages and shows Player Categories", description="Manages and shows Player Categories", add_help=False, exit_on_error=False)
@@ -337,6 +417,29 @@ class ConsoleParser:
         
         self.subparsers["player.get"] = player_section.add_parser(ConsoleParser.PlayerSubcommand.GET, add_help=False, exit_on_error=False, help="Gets the Category of a Player", description="Gets the Category of a Player")
         self.subparsers["player.get"].add_argument("player", type=str, help="The name or GUID of the player")
+        self.subparsers["player.get"].add_argument("--json", action="store_true", dest="json", default=False, help="Output the result as JSON")
+        
+        ## 'ban' command
+        self.subparsers["ban"] = subparser_section.add_parser(ConsoleParser.Command.BAN, help="Bans a player by blacklisting them and kicking them, if they are online", description="Bans a player by blacklisting them and kicking them, if they are online", add_help=False, exit_on_error=False)
+        self.subparsers["ban"].add_argument("player", type=str, help="The GUID or name of the player to ban")
+        self.subparsers["ban"].add_argument("reason", type=str, nargs="?", help="The reason for the ban (Use quotes for multiple words)")
+        
+        ## 'unban' command
+        self.subparsers["unban"] = subparser_section.add_parser(ConsoleParser.Command.UNBAN, help="Unbans a player by setting their category back to unlisted", description="Unbans a player by setting their category back to unlisted", add_help=False, exit_on_error=False)
+        self.subparsers["unban"].add_argument("player", type=str, help="The GUID or name of the player to unban")
+        
+        ## 'broadcast' command
+        self.subparsers["broadcast"] = subparser_section.add_parser(ConsoleParser.Command.BROADCAST, aliases=["say"], help="Sends a message to all players on the server", description="Sends a message to all players on the server", add_help=False, exit_on_error=False)
+        self.subparsers["broadcast"].add_argument("message", type=str, nargs="+", help="The message to send")
+        self.subparsers["say"] = self.subparsers["broadcast"]
+        
+        ## 'events' command
+        self.subparsers["events"] = subparser_section.add_parser(ConsoleParser.Command.EVENTS, help="Shows the most recent server events", description="Shows the most recent server events (e.g. joins, saves and restarts) since the launcher was started", add_help=False, exit_on_error=False)
+        self.subparsers["events"].add_argument("count", type=int, nargs="?", default=10, help="The number of events to show (default: %(default)s)")
+        
+        ## 'raw' command
+        self.subparsers["raw"] = subparser_section.add_parser(ConsoleParser.Command.RAW, help="Sends a command to the console of the Dedicated Server as-is and shows the response", description="Sends a command to the console of the Dedicated Server as-is and shows the response. The command is not validated, so use with care", add_help=False, exit_on_error=False)
+        self.subparsers["raw"].add_argument("command", type=str, nargs="+", help="The console command to send (e.g. DSListGames)")
     
     def get_help(self, cmd=None, subcmd=None):
         """
@@ -413,6 +516,86 @@ class ConsoleParser:
             return True, args
 
 
+#
+#   Console Output
+#
+
+# Matches ANSI escape sequences, such that they can be removed from text, that is not written to a terminal
+ANSI_ESCAPE_REGEX = re.compile(r"\x1b\[[0-9;?]*[A-Za-z]")
+
+class ConsoleOutput:
+    """
+        Class for formatting command output for the console. Can't be instantiated!
+        
+        Arguments:
+            - color: Wether to colorize the output. Disabled, if the NO_COLOR environment variable is set
+    """
+    
+    color = "NO_COLOR" not in os.environ
+    
+    def __new__(cls, *args, **kwargs):
+        """ Override to prevent instantiation """
+        raise TypeError(f"{cls.__name__} cannot be instantiated")
+    
+    @classmethod
+    def set_color(cls, color=True):
+        """ Sets, wether the output should be colorized """
+        
+        cls.color = color
+    
+    @classmethod
+    def colorize(cls, text, color):
+        """ Returns {text} in the given ANSI {color}, if colorized output is enabled """
+        
+        if not cls.color:
+            return text
+        
+        return f"{color}{text}{ansi.reset}"
+    
+    @staticmethod
+    def display_width(text):
+        """
+            Returns the number of columns {text} takes up in the terminal.
+            Escape sequences and combining characters take up no space, while wide characters (e.g. CJK or emoji) take up two columns
+        """
+        
+        width = 0
+        
+        for char in ANSI_ESCAPE_REGEX.sub("", text):
+            if unicodedata.combining(char) or (unicodedata.category(char) in ["Mn", "Me", "Cf"]):
+                continue
+            
+            width += 2 if unicodedata.east_asian_width(char) in ["W", "F"] else 1
+        
+        return width
+    
+    @classmethod
+    def format_table(cls, headers, rows, indent=4):
+        """
+            Formats the given data as a table with aligned columns
+            
+            Arguments:
+                - headers: List of the column headers
+                - rows: List of rows, which are lists of the cell values. Cells may already be colorized
+                - [indent]: Number of spaces to put in front of every line
+            
+            Returns: A list of lines of the table
+        """
+        
+        rows = [[str(cell) for cell in row] for row in rows]
+        
+        widths = [max([cls.display_width(header)] + [cls.display_width(row[i]) for row in rows]) for i, header in enumerate(headers)]
+        
+        def format_row(cells):
+            return (" " * indent) + "  ".join(cell + " " * (widths[i] - cls.display_width(cell)) for i, cell in enumerate(cells)).rstrip()
+        
+        lines = [cls.colorize(format_row(headers), ansi.weight.bold)]
+        lines.append((" " * indent) + "  ".join("-" * w for w in widths))
+        lines += [format_row(row) for row in rows]
+        
+        return lines
+
+
 #
 #   Logging
 #
@@ -438,15 +621,87 @@ SECONDARY_LOG_COLORS = {
     }
 }
 
+# Formats and colors of server events on the console, such that they stand out from other log messages
+EVENT_LOGFORMAT = "[%(asctime)s] [%(name)s/%(levelname)s] * %(message)s"
+EVENT_CLOGFORMAT = "[%(asctime)s] %(log_color)s[%(name)s/%(levelname)s]%(reset)s %(message_log_color)s* %(message)s"
+EVENT_LOGCOLORS = dict(LOGCOLORS, INFO="cyan")
+EVENT_SECONDARY_LOG_COLORS = {
+    "message": dict(SECONDARY_LOG_COLORS["message"], INFO="light_cyan")
+}
+
+# Formats of the 'detailed' console log format, which also shows the date, the thread and the source of messages
+DETAILED_LOGFORMAT = "[%(asctime)s] [%(threadName)s] [%(name)s/%(levelname)s] (%(module)s:%(lineno)d) %(message)s"
+DETAILED_CLOGFORMAT = "[%(asctime)s] [%(threadName)s] %(log_color)s[%(name)s/%(levelname)s]%(reset)s (%(module)s:%(lineno)d) %(message_log_color)s%(message)s"
+DETAILED_EVENT_LOGFORMAT = "[%(asctime)s] [%(threadName)s] [%(name)s/%(levelname)s] (%(module)s:%(lineno)d) * %(message)s"
+DETAILED_EVENT_CLOGFORMAT = "[%(asctime)s] [%(threadName)s] %(log_color)s[%(name)s/%(levelname)s]%(reset)s (%(module)s:%(lineno)d) %(message_log_color)s* %(message)s"
+DETAILED_DATEFORMAT = "%Y-%m-%d %H:%M:%S"
+
+class LogFormat(Enum):
+    """ Represents, how log messages are formatted on the console """
+    
+    COMPACT = "compact"
+    DETAILED = "detailed"
+    JSON = "json"
+
+class PlainFormatter(logging.Formatter):
+    """ Formatter, that removes ANSI escape sequences from the messages, such that colorized output doesn't end up in log files """
+    
+    def format(self, record):
+        return ANSI_ESCAPE_REGEX.sub("", super().format(record))
+
+class ConsoleFormatter(logging.Formatter):
+    """
+        Formatter, that formats server events logged by the LoggingNotificationHandler differently than other log messages
+        
+        Arguments:
+            - formatter: The formatter to use for normal log messages
+            - event_formatter: The formatter to use for server events
+    """
+    
+    def __init__(self, formatter, event_formatter):
+        super().__init__()
+        
+        self.formatter = formatter
+        self.event_formatter = event_formatter
+    
+    def format(self, record):
+        if getattr(record, "event_type", None) is not None:
+            return self.event_formatter.format(record)
+        
+        return self.formatter.format(record)
+
+class JSONFormatter(logging.Formatter):
+    """ Formatter, that formats every log message as a single line JSON object, e.g. for log collectors """
+    
+    def format(self, record):
+        entry = {
+            "time": datetime.fromtimestamp(record.created, timezone.utc).isoformat(),
+            "level": record.levelname,
+            "logger": record.name,
+            "thread": record.threadName,
+            "message": ANSI_ESCAPE_REGEX.sub("", record.getMessage())
+        }
+        
+        if getattr(record, "event_type", None) is not None:
+            entry["event_type"] = record.event_type
+        
+        if record.exc_info:
+            entry["exception"] = self.formatException(record.exc_info)
+        
+        return json.dumps(entry)
+
 class LauncherLogging:
     """
         Class for managing logging. Can't be instantiated!
         
         Arguments:
             - log_debug: Wether to include log messages with level logging.DEBUG
+            - verbosity: Adjustment of the console log level. -1 only shows errors, 1 shows debug messages and 2 also includes debug messages in the log file
     """
     
     log_debug = True
+    verbosity = 0
+    console_format = LogFormat.COMPACT
     
     handlers = {
             "out_console": None,
@@ -458,7 +713,12 @@ class LauncherLogging:
     
     # Formatters
     colorformatter = colorlog.ColoredFormatter(CLOGFORMAT, datefmt=DATEFORMAT, log_colors=LOGCOLORS, secondary_log_colors=SECONDARY_LOG_COLORS)
-    plainformatter = logging.Formatter(LOGFORMAT, datefmt=DATEFORMAT)
+    plainformatter = PlainFormatter(LOGFORMAT, datefmt=DATEFORMAT)
+    colorconsoleformatter = ConsoleFormatter(colorformatter, colorlog.ColoredFormatter(EVENT_CLOGFORMAT, datefmt=DATEFORMAT, log_colors=EVENT_LOGCOLORS, secondary_log_colors=EVENT_SECONDARY_LOG_COLORS))
+    plainconsoleformatter = ConsoleFormatter(plainformatter, PlainFormatter(EVENT_LOGFORMAT, datefmt=DATEFORMAT))
+    colordetailedformatter = ConsoleFormatter(colorlog.ColoredFormatter(DETAILED_CLOGFORMAT, datefmt=DETAILED_DATEFORMAT, log_colors=LOGCOLORS, secondary_log_colors=SECONDARY_LOG_COLORS), colorlog.ColoredFormatter(DETAILED_EVENT_CLOGFORMAT, datefmt=DETAILED_DATEFORMAT, log_colors=EVENT_LOGCOLORS, secondary_log_colors=EVENT_SECONDARY_LOG_COLORS))
+    plaindetailedformatter = ConsoleFormatter(PlainFormatter(DETAILED_LOGFORMAT, datefmt=DETAILED_DATEFORMAT), PlainFormatter(DETAILED_EVENT_LOGFORMAT, datefmt=DETAILED_DATEFORMAT))
+    jsonformatter = JSONFormatter()
     
     def __new__(cls, *args, **kwargs):
         """ Override to prevent instantiation """
@@ -486,15 +746,80 @@ class LauncherLogging:
         """
         
         cls.log_debug = log_debug
+        cls.update_levels()
+    
+    @classmethod
+    def set_verbosity(cls, verbosity=0):
+        """
+            Set the adjustment of the console log level
+            
+            Arguments:
+                - verbosity: -1 to only show errors, 0 for the default level, 1 to show debug messages, 2 to also write debug messages to the log file
+        """
+        
+        cls.verbosity = verbosity
+        cls.update_levels()
+    
+    @classmethod
+    def set_console_format(cls, console_format=LogFormat.COMPACT):
+        """
+            Set how log messages are formatted on the console
+            
+            Arguments:
+                - console_format: The LogFormat to use
+        """
         
-        # Set levels for out_console and logfile handlers, but NOT for err_console handler
-        level = logging.DEBUG if cls.log_debug else logging.INFO
+        cls.console_format = console_format
+        
+        formatter = cls.get_console_formatter()
+        
+        for handler in ["out_console", "err_console"]:
+            if cls.handlers[handler]:
+                cls.handlers[handler].setFormatter(formatter)
+    
+    @classmethod
+    def get_console_formatter(cls):
+        """ Returns the formatter for the console handlers matching the console format and wether colors are enabled """
+        
+        if cls.console_format == LogFormat.JSON:
+            return cls.jsonformatter
+        elif cls.console_format == LogFormat.DETAILED:
+            return cls.colordetailedformatter if ConsoleOutput.color else cls.plaindetailedformatter
+        else:
+            return cls.colorconsoleformatter if ConsoleOutput.color else cls.plainconsoleformatter
+    
+    @classmethod
+    def get_console_level(cls):
+        """ Returns the log level for the out_console handler """
+        
+        if cls.verbosity < 0:
+            return logging.ERROR
+        elif cls.log_debug or (cls.verbosity > 0):
+            return logging.DEBUG
+        else:
+            return logging.INFO
+    
+    @classmethod
+    def get_logfile_level(cls):
+        """ Returns the log level for the logfile handler """
+        
+        return logging.DEBUG if (cls.log_debug or (cls.verbosity > 1)) else logging.INFO
+    
+    @classmethod
+    def logs_debug_to_file(cls):
+        """ Returns wether debug messages are written to a log file right now """
+        
+        return (cls.handlers["logfile"] is not None) and (cls.get_logfile_level() <= logging.DEBUG)
+    
+    @classmethod
+    def update_levels(cls):
+        """ Applies the current log levels to the out_console and logfile handlers, but NOT to the err_console handler """
         
         if cls.handlers["out_console"]:
-            cls.handlers["out_console"].setLevel(level)
+            cls.handlers["out_console"].setLevel(cls.get_console_level())
         
         if cls.handlers["logfile"]:
-            cls.handlers["logfile"].setLevel(level)
+            cls.handlers["logfile"].setLevel(cls.get_logfile_level())
 
     @staticmethod
     def get_logfile_path(log_path, base_filename=None, ending="log"):
@@ -516,11 +841,12 @@ class LauncherLogging:
         
         log_filename= f"{base_string}{datetime_string}"
         
-        # If file with name already exists, add increasing integer until free file is found
+        # If file with name already exists, add increasing integer until free file is found.
+        # Compressed log files count as existing too, as compressing a new file with the same name would overwrite them
         i = 1
         logfile_path = os.path.join(log_path, f"{log_filename}.{ending}")
         
-        while os.path.exists(logfile_path):
+        while os.path.exists(logfile_path) or os.path.exists(f"{logfile_path}.gz"):
             # Failsave to not create endless loop
             if i > 1000000:
                 raise FileExistsError("All log files with added integers up to 1000000 already exist, what are you doing?!")
@@ -536,17 +862,19 @@ class LauncherLogging:
             Setup (colored) logging formats for console output using the logging module
         """
         
+        formatter = cls.get_console_formatter()
+        
         # Initialize handler for standard out (Non-error console)
         cls.handlers["out_console"] = logging.StreamHandler(sys.stdout)
-        cls.handlers["out_console"].setFormatter(cls.colorformatter)
-        cls.handlers["out_console"].setLevel(logging.DEBUG if cls.log_debug else logging.INFO)
+        cls.handlers["out_console"].setFormatter(formatter)
+        cls.handlers["out_console"].setLevel(cls.get_console_level())
         cls.handlers["out_console"].addFilter(lambda record: record.levelno <= logging.WARNING)
         
         logging.getLogger().addHandler(cls.handlers["out_console"])
         
         # Initialize handler for standard error (Error console)
         cls.handlers["err_console"] = logging.StreamHandler(sys.stderr)
-        cls.handlers["err_console"].setFormatter(cls.colorformatter)
+        cls.handlers["err_console"].setFormatter(formatter)
         cls.handlers["err_console"].setLevel(logging.ERROR)
         
         logging.getLogger().addHandler(cls.handlers["err_console"])
@@ -558,21 +886,99 @@ class LauncherLogging:
             
             Arguments:
                 - log_path: Path to a directory to store logs at
+            
+            Returns: A boolean indicating, wether logging to a file was set up. If not, only the console is logged to
         """
+        
+        try:
+            # Create logfile path if not existing yet
+            if not os.path.exists(log_path):
+                os.makedirs(log_path)
             
-        # Create logfile path if not existing yet
-        if not os.path.exists(log_path):
-            os.makedirs(log_path)
-
-        logfile_path = LauncherLogging.get_logfile_path(log_path, "astrotux")
-
-        cls.handlers["logfile"] = logging.FileHandler(logfile_path)
+            logfile_path = LauncherLogging.get_logfile_path(log_path, "astrotux")
+            
+            cls.handlers["logfile"] = logging.FileHandler(logfile_path)
+        except (OSError, ValueError) as e:
+            # Running without log file is better than not running at all, e.g. in containers with read-only directories
+            LOGGER.warning("#" * 60)
+            LOGGER.warning(f"Could not set up logging to a file in '{log_path}': {str(e)}")
+            LOGGER.warning("Only logging to the console! Check the LogPath config option")
+            LOGGER.warning("#" * 60)
+            return False
+        
         cls.handlers["logfile"].setFormatter(cls.plainformatter)
-        cls.handlers["logfile"].setLevel(logging.DEBUG if cls.log_debug else logging.INFO)
+        cls.handlers["logfile"].setLevel(cls.get_logfile_level())
 
         logging.getLogger().addHandler(cls.handlers["logfile"])
         
         cls.logfile_path = logfile_path
+        
+        return True
+    
+    @classmethod
+    def roll_logfile(cls):
+        """
+            Closes the current log file and continues logging to a new one in the same directory
+            
+            Returns: A boolean indicating, wether logging to a new file was set up
+        """
+        
+        if cls.handlers["logfile"] is None:
+            return False
+        
+        log_path = os.path.dirname(cls.logfile_path)
+        
+        logging.getLogger().removeHandler(cls.handlers["logfile"])
+        cls.handlers["logfile"].close()
+        
+        cls.handlers["logfile"] = None
+        cls.logfile_path = None
+        
+        return cls.setup_logfile(log_path)
+    
+    @classmethod
+    def compress_old_logs(cls, log_path, keep_uncompressed=0, base_filename="astrotux"):
+        """
+            Compresses old log files in {log_path} using gzip, except the current one and the newest {keep_uncompressed} others
+            
+            Arguments:
+                - log_path: Path to the directory the logs are stored in
+                - [keep_uncompressed]: Number of old log files to keep uncompressed for easy reading
+                - [base_filename]: The base filename of the log files to compress
+        """
+        
+        try:
+            log_files = [os.path.join(log_path, f) for f in os.listdir(log_path) if f.startswith(f"{base_filename}_") and f.endswith(".log")]
+        except OSError as e:
+            LOGGER.warning(f"Could not read log directory to compress old log files: {str(e)}")
+            return
+        
+        # The current log file is still written to
+        log_files = [f for f in log_files if (cls.logfile_path is None) or not os.path.samefile(f, cls.logfile_path)]
+        log_files.sort(key=os.path.getmtime, reverse=True)
+        
+        for log_file in log_files[keep_uncompressed:]:
+            gz_path = f"{log_file}.gz"
+            
+            # Never overwrite a compressed log file, e.g. if a log file with the same name was created again after compressing the first one
+            i = 1
+            
+            while os.path.exists(gz_path):
+                gz_path = f"{log_file[:-len('.log')]}_{i}.log.gz"
+                i += 1
+            
+            try:
+                with open(log_file, "rb") as lf, gzip.open(gz_path, "wb") as gf:
+                    shutil.copyfileobj(lf, gf)
+                
+                # Keep modification time, such that the order of log files stays the same
+                stat = os.stat(log_file)
+                os.utime(gz_path, (stat.st_atime, stat.st_mtime))
+                
+                os.remove(log_file)
+                LOGGER.debug(f"Compressed old log file '{log_file}'")
+            except OSError as e:
+                LOGGER.warning(f"Could not compress old log file '{log_file}': {str(e)}")
 
 #
 #   Notifications
@@ -580,6 +986,7 @@ class LauncherLogging:
 
 class EventType(Enum):
     MESSAGE = "message"
+    SERVER_STARTING = "server_starting"
     START = "start"
     REGISTERED = "registered"
     SHUTDOWN = "shutdown"
@@ -589,6 +996,22 @@ class EventType(Enum):
     COMMAND = "command"
     SAVE = "save"
     SAVEGAME_CHANGE = "savegame_change"
+    PLAYER_BANNED = "player_banned"
+    SHUTDOWN_WARNING = "shutdown_warning"
+    RESTARTED = "server_restarted"
+    CRASH_LOOP = "crash_loop"
+    BACKUP_CREATED = "backup_created"
+    UPDATE_INSTALLED = "update_installed"
+    AUTOSAVE = "autosave"
+    SERVER_ERROR = "server_error"
+    SERVER_CONFIG_CHANGE = "server_config_change"
+    EXTERNAL_SAVE = "external_save"
+    MAINTENANCE_MODE = "maintenance_mode"
+    INSTALL_INCOMPLETE = "install_incomplete"
+    HEARTBEAT = "heartbeat"
+    KICK_ALL = "kick_all"
+    RESTART_ON_EVENT = "restart_on_event"
+    CONFIG_RELOADED = "config_reloaded"
 
 class NotificationManager:
     """ Class that keeps multiple Notification Handlers and broadcasts messages to all of them """
@@ -603,12 +1026,40 @@ class NotificationManager:
     def clear(self):
         self.handlers.clear()
     
+    def stop(self, timeout=None):
+        """ Stops all registered notification handlers, waiting at most {timeout} seconds for each of them """
+        for handler in self.handlers:
+            handler.stop(timeout)
+    
     def send_event(self, event_type=EventType.MESSAGE, **params):
         """ Send event to all registered notification handlers """
         for handler in self.handlers:
             handler.send_event(event_type, **params)
 
 
+# Event parameters, that are added to every event and therefore not part of the event fields
+COMMON_EVENT_PARAMS = ["name", "server_version"]
+
+def get_event_fields(params):
+    """
+        Returns the parameters of an event, that are specific to the event, as a dictionary of strings
+        
+        Arguments:
+            - params: The parameters passed along with the event
+    """
+    
+    return {key: str(value) for key, value in params.items() if key not in COMMON_EVENT_PARAMS}
+
+def valid_format(format_string):
+    """ Returns wether {format_string} can be used as a format string, i.e. all braces are matched or escaped """
+    
+    try:
+        list(Formatter().parse(format_string))
+    except ValueError:
+        return False
+    
+    return True
+
 def safeformat(string, keep_escape=True, **kwargs):
     """
         Formats the passed string {str} using the given keyword arguments, while keeping missing replacements unformatted
@@ -626,17 +1077,42 @@ def safeformat(string, keep_escape=True, **kwargs):
     
     return string.format_map(replacements)
 
+def escape_json_string(string):
+    """
+        Escapes the passed string {string}, such that it can be inserted into a string of a JSON message template.
+        Braces aren't escaped, so format fields in it are still replaced
+    """
+    
+    return json.dumps(string)[1:-1]
+
 DEFAULT_EVENT_FORMATS = {
         EventType.MESSAGE           : "{message}",
+        EventType.SERVER_STARTING   : "Server starting... (Version: {server_version}, Port: {server_port}, Console Port: {console_port}, WINE prefix: {wine_prefix}, Encryption: {encryption})",
         EventType.START             : "Server started!",
         EventType.REGISTERED        : "Server registered with Playfab!",
         EventType.SHUTDOWN          : "Server shutting down...",
         EventType.CRASH             : "Server crashed!",
-        EventType.PLAYER_JOIN       : "Player '{player_name}'({player_guid}) joined the game",
-        EventType.PLAYER_LEAVE      : "Player '{player_name}'({player_guid}) left the game",
+        EventType.PLAYER_JOIN       : "Player '{player_name}'({player_guid}) joined the game ({player_count}/{max_players} online)",
+        EventType.PLAYER_LEAVE      : "Player '{player_name}'({player_guid}) left the game ({player_count}/{max_players} online)",
         EventType.COMMAND           : "Command executed: {command}",
         EventType.SAVE              : "Game saved!",
-        EventType.SAVEGAME_CHANGE   : "Savegame changed to '{savegame_name}'"
+        EventType.SAVEGAME_CHANGE   : "Savegame changed to '{savegame_name}'",
+        EventType.PLAYER_BANNED     : "Player '{player_name}'({player_guid}) was banned (Reason: {reason})",
+        EventType.SHUTDOWN_WARNING  : "Server {action} in {seconds} seconds",
+        EventType.RESTARTED         : "Server restarted",
+        EventType.CRASH_LOOP        : "Server crashed {restarts} times within {window} seconds! Not restarting automatically anymore",
+        EventType.BACKUP_CREATED    : "Backup created: {backup_name}",
+        EventType.UPDATE_INSTALLED  : "Server updated from {old_version} to {new_version}",
+        EventType.AUTOSAVE          : "Game saved automatically",
+        EventType.SERVER_ERROR      : "Server problem detected: {message}",
+        EventType.SERVER_CONFIG_CHANGE : "Server setting '{setting}' changed to '{value}'. Takes effect after restart",
+        EventType.CONFIG_RELOADED   : "Whitelist and player categories reloaded ({changes} changes)",
+        EventType.EXTERNAL_SAVE     : "Game saved on external request",
+        EventType.MAINTENANCE_MODE  : "Server started in maintenance mode, only whitelisted players can join",
+        EventType.INSTALL_INCOMPLETE : "Server installation is incomplete or damaged ({problems}), it has to be installed again",
+        EventType.HEARTBEAT         : "Server is running ({player_count} players online)",
+        EventType.KICK_ALL          : "All players were kicked ({player_count} players, Reason: {reason})",
+        EventType.RESTART_ON_EVENT  : "Server restarting, because '{trigger}' was detected"
     }
 
 
@@ -656,26 +1132,163 @@ class NotificationHandler:
         self.name = name
         self.whitelist = event_whitelist
         self.formats = event_formats
+        self.level = logging.NOTSET
+    
+    def set_level(self, level):
+        """ Sets the minimum logging level an event has to be mapped to in DEFAULT_LEVEL_MAPPING to be sent """
+        
+        self.level = level
+    
+    def set_templates(self, templates):
+        """ Overrides the format strings of the events in {templates}, which maps EventType's to format strings. Other events keep their format """
+        
+        self.formats = {**self.formats, **templates}
+    
+    def stop(self, timeout=None):
+        """ Stops handling events. To be overwritten by subclasses, that handle events asynchronously """
+        
+        pass
+    
+    def accepts(self, event_type):
+        """ Returns wether an event of the type {event_type} is in the whitelist and reaches the minimum level of the handler """
+        
+        return (event_type in self.whitelist) and (DEFAULT_LEVEL_MAPPING.get(event_type, logging.INFO) >= self.level)
     
     def send_event(self, event_type=EventType.MESSAGE, **params):
         """ Send event using the provided parameters """
         
-        # Only send, if event is in whitelist
-        if event_type in self.whitelist:
+        # Only send, if event is in whitelist and reaches the minimum level
+        if self.accepts(event_type):
             # Add server name to parameters for formatting
             params["name"] = self.name
             message = safeformat(self.formats[event_type], **params)
             
-            self._send_message(event_type, message)
+            self._send_message(event_type, message, get_event_fields(params))
     
-    def _send_message(self, event_type, message):
+    def _send_message(self, event_type, message, fields={}):
         """
             Internal method to actually pass the message on.
             To be overwritten by subclasses.
+            
+            Arguments:
+                - event_type: The type of the event
+                - message: The formatted message
+                - [fields]: Dictionary of the event parameters as strings, except the ones every event has
         """
         
         print(message)
 
+class NotificationSpool:
+    """
+        Append-only file storing the queued notifications of a handler until they are sent,
+        such that notifications, that weren't sent before the launcher crashed or was killed, can be sent after a restart.
+        Use NotificationSpool.open to get the spool for a file, as one file must only be used by one spool object
+        
+        Arguments:
+            - spool_path: Path of the spool file
+    """
+    
+    _spools = {}
+    _spools_lock = threading.Lock()
+    
+    @staticmethod
+    def open(spool_path):
+        """ Returns the spool for the file at {spool_path}, creating it, if it doesn't exist yet """
+        
+        spool_path = os.path.abspath(spool_path)
+        
+        with NotificationSpool._spools_lock:
+            if spool_path not in NotificationSpool._spools:
+                NotificationSpool._spools[spool_path] = NotificationSpool(spool_path)
+            
+            return NotificationSpool._spools[spool_path]
+    
+    def __init__(self, spool_path):
+        self.spool_path = spool_path
+        self.lock = threading.Lock()
+        self.entries = {}
+        self.in_use = False
+        
+        self._load()
+    
+    def _load(self):
+        """ Reads the unsent entries from the spool file and compacts it """
+        
+        if os.path.isfile(self.spool_path):
+            with open(self.spool_path, "r") as sf:
+                for line in sf:
+                    try:
+                        entry = json.loads(line)
+                    except json.JSONDecodeError:
+                        # The last line may be incomplete, if the launcher was killed while writing it
+                        continue
+                    
+                    if entry.get("sent", False):
+                        self.entries.pop(entry["id"], None)
+                    else:
+                        self.entries[entry["id"]] = entry
+        
+        os.makedirs(os.path.dirname(self.spool_path), exist_ok=True)
+        
+        # Only keep unsent entries, such that the file doesn't grow indefinitely
+        with open(self.spool_path, "w") as sf:
+            for entry in self.entries.values():
+                sf.write(json.dumps(entry) + "\n")
+    
+    def _append(self, entry):
+        with open(self.spool_path, "a") as sf:
+            sf.write(json.dumps(entry) + "\n")
+            sf.flush()
+            os.fsync(sf.fileno())
+    
+    def add(self, event_type, message, fields={}):
+        """
+            Stores a notification in the spool
+            
+            Returns: The ID of the entry
+        """
+        
+        entry = {
+            "id": str(uuid.uuid4()),
+            "timestamp": datetime.now(timezone.utc).isoformat(),
+            "level": logging.getLevelName(DEFAULT_LEVEL_MAPPING.get(event_type, logging.INFO)).lower(),
+            "event": event_type.value,
+            "message": message,
+            "fields": fields
+        }
+        
+        with self.lock:
+            self.entries[entry["id"]] = entry
+            self._append(entry)
+        
+        return entry["id"]
+    
+    def remove(self, entry_id):
+        """ Marks the entry with the ID {entry_id} as sent """
+        
+        with self.lock:
+            if self.entries.pop(entry_id, None) is not None:
+                self._append({"id": entry_id, "sent": True})
+    
+    def take_unsent(self):
+        """ Returns the entries, that weren't sent yet, and marks the spool as used. Returns no entries, if the spool is used by a handler already """
+        
+        with self.lock:
+            if self.in_use:
+                return []
+            
+            self.in_use = True
+            return list(self.entries.values())
+    
+    def release(self):
+        """ Marks the spool as not used anymore, such that the next handler taking the unsent entries sends them """
+        
+        with self.lock:
+            self.in_use = False
+
+# Time in seconds to wait before retrying to send a notification. Increases with every try
+NOTIFICATION_RETRY_DELAY = 10
+
 class QueuedNotificationHandler(NotificationHandler):
     """
         Notification handler that uses a thread and a queue to handle events asynchronously
@@ -691,25 +1304,88 @@ class QueuedNotificationHandler(NotificationHandler):
             self.callback = callback
             self.event_queue = Queue()
             self.wakeup_event = threading.Event()
+            self.spool = None
+            self.retries = 0
+            self.retry_list = []    # Events, that couldn't be sent, as (time of next try, event) tuples
+            self.running = True
+            self.current_event_id = None    # ID of the event being sent right now, which stays the same, when the event is retried
             
             super(QueuedNotificationHandler.NotificationThread, self).__init__(name=name)
             self.daemon = True
             self.start()
         
-        def add_event(self, event_type, message):
-            """ Add an event to the internal queue """
-            self.event_queue.put((event_type, message))
+        def add_event(self, event_type, message, fields={}, spool_id=None):
+            """ Add an event to the internal queue and the spool, if one is set and the event is not from the spool already """
+            
+            if (self.spool is not None) and (spool_id is None):
+                try:
+                    spool_id = self.spool.add(event_type, message, fields)
+                except Exception as e:
+                    logging.getLogger("Notify").error(f"Error while writing notification to spool file: {str(e)}")
+            
+            # Events from the spool keep their ID, such that they can be recognized after a restart
+            event_id = spool_id if spool_id is not None else str(uuid.uuid4())
+            
+            self.event_queue.put((event_type, message, fields, event_id, spool_id, 0))
+            self.wakeup_event.set()
+        
+        def retry_event(self, event):
+            """ Queues the {event}, that couldn't be sent, again after a delay, if it wasn't retried too often yet """
+            
+            event_type, message, fields, event_id, spool_id, tries = event
+            
+            if tries >= self.retries:
+                logging.getLogger("Notify").error(f"Giving up on sending '{event_type.value}' notification")
+                return
+            
+            delay = NOTIFICATION_RETRY_DELAY * (tries + 1)
+            logging.getLogger("Notify").warning(f"Sending '{event_type.value}' notification failed, trying again in {delay} seconds")
+            
+            self.retry_list.append((time.time() + delay, (event_type, message, fields, event_id, spool_id, tries + 1)))
+        
+        def stop(self):
+            """ Stops handling events after the current one """
+            
+            self.running = False
             self.wakeup_event.set()
         
+        def pending_count(self):
+            """ Returns the number of events, that were not sent yet """
+            
+            return self.event_queue.qsize() + len(self.retry_list)
+        
         def run(self):
-            while True:
+            while self.running:
+                # Queue events again, whose retry delay has passed
+                now = time.time()
+                
+                for retry in [r for r in self.retry_list if r[0] <= now]:
+                    self.retry_list.remove(retry)
+                    self.event_queue.put(retry[1])
+                
                 if not self.event_queue.empty():
                     # If the queue is not empty, there are events to handle
                     event = self.event_queue.get()
-                    self.callback(*event)
+                    event_type, message, fields, event_id, spool_id, _ = event
+                    self.current_event_id = event_id
+                    
+                    # An error while handling one event must not stop the handling of the following ones
+                    try:
+                        # Handlers return False, if the notification couldn't be sent, so it is kept in the spool
+                        success = self.callback(event_type, message, fields) is not False
+                    except Exception as e:
+                        logging.getLogger("Notify").error(f"Error while handling notification: {str(e)}")
+                        success = False
+                    
+                    if not success:
+                        self.retry_event(event)
+                    elif spool_id is not None:
+                        self.spool.remove(spool_id)
                 else:
-                    # If queue is empty, sleep for 10s or until the wakeup_event is set
-                    self.wakeup_event.wait(timeout=10)
+                    # If queue is empty, sleep for 10s, until the next retry or until the wakeup_event is set
+                    timeout = min([10] + [r[0] - now for r in self.retry_list])
+                    
+                    self.wakeup_event.wait(timeout=max(timeout, 0))
                     self.wakeup_event.clear()
     
     def __init__(self, name="Server", event_whitelist=set([e for e in EventType]), event_formats=DEFAULT_EVENT_FORMATS):
@@ -717,18 +1393,59 @@ class QueuedNotificationHandler(NotificationHandler):
         
         self.thread = QueuedNotificationHandler.NotificationThread(self._send_message)
     
+    def stop(self, timeout=None):
+        """
+            Stops the thread handling the events, e.g. before replacing the handler after the config was reloaded.
+            Events, that were not sent yet, stay in the spool file, if one is used, and are sent by the next handler using it
+        """
+        
+        self.thread.stop()
+        self.thread.join(timeout)
+        
+        pending = self.thread.pending_count()
+        
+        if self.thread.spool is not None:
+            self.thread.spool.release()
+        elif pending > 0:
+            logging.getLogger("Notify").warning(f"Discarding {pending} notification(s), that were not sent yet")
+    
+    def set_retries(self, retries):
+        """ Sets how often sending an event is tried again, if it failed """
+        
+        self.thread.retries = retries
+    
+    def set_spool_file(self, spool_path):
+        """
+            Stores queued events in the spool file at {spool_path} until they are sent
+            and queues the events, that were not sent the last time the file was used
+        """
+        
+        self.thread.spool = NotificationSpool.open(spool_path)
+        
+        unsent = self.thread.spool.take_unsent()
+        
+        if len(unsent) > 0:
+            logging.getLogger("Notify").info(f"Sending {len(unsent)} notification(s), that were not sent before")
+        
+        for entry in unsent:
+            try:
+                self.thread.add_event(EventType(entry["event"]), entry["message"], entry["fields"], spool_id=entry["id"])
+            except ValueError:
+                # Event type doesn't exist anymore
+                self.thread.spool.remove(entry["id"])
+    
     def send_event(self, event_type=EventType.MESSAGE, **params):
         """ Send event using the provided parameters """
         
-        # Only send, if event is in whitelist
-        if event_type in self.whitelist:
+        # Only send, if event is in whitelist and reaches the minimum level
+        if self.accepts(event_type):
             # Add server name to parameters for formatting
             params["name"] = self.name
             message = safeformat(self.formats[event_type], **params)
             
-            self.thread.add_event(event_type, message)
+            self.thread.add_event(event_type, message, get_event_fields(params))
     
-    def _send_message(self, event_type, message):
+    def _send_message(self, event_type, message, fields={}):
         """
             Method for handling events asynchronously.
             To be overritten by subclasses.
@@ -739,6 +1456,7 @@ class QueuedNotificationHandler(NotificationHandler):
 
 DEFAULT_LEVEL_MAPPING = {
         EventType.MESSAGE           : logging.INFO,
+        EventType.SERVER_STARTING   : logging.INFO,
         EventType.START             : logging.INFO,
         EventType.REGISTERED        : logging.INFO,
         EventType.SHUTDOWN          : logging.INFO,
@@ -747,11 +1465,37 @@ DEFAULT_LEVEL_MAPPING = {
         EventType.PLAYER_LEAVE      : logging.INFO,
         EventType.COMMAND           : logging.INFO,
         EventType.SAVE              : logging.INFO,
-        EventType.SAVEGAME_CHANGE   : logging.INFO
+        EventType.SAVEGAME_CHANGE   : logging.INFO,
+        EventType.PLAYER_BANNED     : logging.INFO,
+        EventType.SHUTDOWN_WARNING  : logging.INFO,
+        EventType.RESTARTED         : logging.INFO,
+        EventType.CRASH_LOOP        : logging.CRITICAL,
+        EventType.BACKUP_CREATED    : logging.INFO,
+        EventType.UPDATE_INSTALLED  : logging.INFO,
+        EventType.AUTOSAVE          : logging.DEBUG,
+        EventType.SERVER_ERROR      : logging.ERROR,
+        EventType.SERVER_CONFIG_CHANGE : logging.INFO,
+        EventType.CONFIG_RELOADED   : logging.INFO,
+        EventType.EXTERNAL_SAVE     : logging.INFO,
+        EventType.MAINTENANCE_MODE  : logging.INFO,
+        EventType.INSTALL_INCOMPLETE : logging.WARNING,
+        EventType.HEARTBEAT         : logging.DEBUG,
+        EventType.KICK_ALL          : logging.INFO,
+        EventType.RESTART_ON_EVENT  : logging.WARNING
+    }
+
+# Names of the levels, that can be set as minimum level of notification methods in the config
+NOTIFICATION_LEVELS = {
+        "debug"     : logging.DEBUG,
+        "info"      : logging.INFO,
+        "warning"   : logging.WARNING,
+        "error"     : logging.ERROR,
+        "critical"  : logging.CRITICAL
     }
 
 LOGGING_DEFAULT_EVENT_WHITELIST = set([
         EventType.MESSAGE,
+        EventType.SERVER_STARTING,
         EventType.START,
         EventType.REGISTERED,
         EventType.SHUTDOWN,
@@ -759,9 +1503,27 @@ LOGGING_DEFAULT_EVENT_WHITELIST = set([
         EventType.PLAYER_JOIN,
         EventType.PLAYER_LEAVE,
         EventType.SAVE,
-        EventType.SAVEGAME_CHANGE
+        EventType.SAVEGAME_CHANGE,
+        EventType.PLAYER_BANNED,
+        EventType.SHUTDOWN_WARNING,
+        EventType.RESTARTED,
+        EventType.CRASH_LOOP,
+        EventType.BACKUP_CREATED,
+        EventType.UPDATE_INSTALLED,
+        EventType.AUTOSAVE,
+        EventType.SERVER_ERROR,
+        EventType.SERVER_CONFIG_CHANGE,
+        EventType.CONFIG_RELOADED,
+        EventType.EXTERNAL_SAVE,
+        EventType.MAINTENANCE_MODE,
+        EventType.INSTALL_INCOMPLETE,
+        EventType.KICK_ALL,
+        EventType.RESTART_ON_EVENT
 ])
 
+# Default name of the logger, that events are logged with
+EVENT_LOGGER_NAME = "Notify"
+
 class LoggingNotificationHandler(NotificationHandler):
     """
         Notification handler that logs events using the logging module
@@ -771,19 +1533,85 @@ class LoggingNotificationHandler(NotificationHandler):
             - event_whitelist: see NotificationHandler class
             - event_formats: see NotificationHandler class
             - level_mapping: Mapping from EventType to a logging level
+            - logger_name: Name of the logger to log events with
     """
     
-    def __init__(self, name="Server", event_whitelist=LOGGING_DEFAULT_EVENT_WHITELIST, event_formats=DEFAULT_EVENT_FORMATS, level_mapping=DEFAULT_LEVEL_MAPPING):
+    def __init__(self, name="Server", event_whitelist=LOGGING_DEFAULT_EVENT_WHITELIST, event_formats=DEFAULT_EVENT_FORMATS, level_mapping=DEFAULT_LEVEL_MAPPING, logger_name=EVENT_LOGGER_NAME):
         super().__init__(name, event_whitelist, event_formats)
         
         self.level_mapping = level_mapping
         
-        self.logger = logging.getLogger("Notify")
+        self.logger = logging.getLogger(logger_name)
     
-    def _send_message(self, event_type, message):
+    def _send_message(self, event_type, message, fields={}):
         level = self.level_mapping[event_type]
         
-        self.logger.log(level, message)
+        self.logger.log(level, message, extra={"event_type": event_type})
+
+class EventHistoryHandler(NotificationHandler):
+    """
+        Notification handler that keeps the most recent events in memory, such that they can be shown using the 'events' command
+        
+        Arguments:
+            - size: The maximum number of events to keep
+            - name: see NotificationHandler class
+            - event_whitelist: see NotificationHandler class
+            - event_formats: see NotificationHandler class
+    """
+    
+    def __init__(self, size, name="Server", event_whitelist=LOGGING_DEFAULT_EVENT_WHITELIST, event_formats=DEFAULT_EVENT_FORMATS):
+        super().__init__(name, event_whitelist, event_formats)
+        
+        self.events = deque(maxlen=size)
+        self.lock = threading.Lock()
+    
+    def set_size(self, size):
+        """ Changes the maximum number of events to keep, dropping the oldest events if necessary """
+        
+        with self.lock:
+            self.events = deque(self.events, maxlen=size)
+    
+    def get_events(self, count=None):
+        """
+            Returns the most recent events as a list of (time, event type, message) tuples, oldest first
+            
+            Arguments:
+                - [count]: The maximum number of events to return (Default: All kept events)
+        """
+        
+        with self.lock:
+            events = list(self.events)
+        
+        if count is not None:
+            events = events[-count:] if count > 0 else []
+        
+        return events
+    
+    def _send_message(self, event_type, message, fields={}):
+        with self.lock:
+            self.events.append((datetime.now(), event_type, message))
+
+class EventTriggerHandler(NotificationHandler):
+    """
+        Notification handler that calls a function, when one of the given events occurs.
+        Server errors can also be selected individually using their ID.
+        
+        Arguments:
+            - triggers: List of event types and server error IDs, that should cause the function to be called
+            - callback: Function to call with the matched event type or server error ID
+    """
+    
+    def __init__(self, triggers, callback):
+        super().__init__("Trigger")
+        
+        self.triggers = set(triggers)
+        self.callback = callback
+    
+    def _send_message(self, event_type, message, fields={}):
+        if event_type.value in self.triggers:
+            self.callback(event_type.value)
+        elif (event_type == EventType.SERVER_ERROR) and (fields.get("error") in self.triggers):
+            self.callback(fields["error"])
 
 DISCORD_MESSAGE_TEMPLATE = """{{
     "content": null,
@@ -814,10 +1642,12 @@ DISCORD_MESSAGE_TEMPLATE = """{{
     ],
     "username": "{name}",
     "avatar_url": "https://astroneer.wiki.gg/images/7/74/Icon_Astroneer.png",
-    "attachments": [],
-    "flags": 4096
+    "attachments": []
 }}"""
 
+# Message flag, that makes Discord post the message without sending push notifications to the channel members
+DISCORD_SUPPRESS_NOTIFICATIONS_FLAG = 1 << 12
+
 DISCORD_HEADERS = {
     'content-type': 'application/json; charset=utf-8',
     'User-Agent': f"AstroTuxLauncher/{LAUNCHER_VERSION}",
@@ -826,6 +1656,7 @@ DISCORD_HEADERS = {
 
 DISCORD_EVENT_EXTRA_MAPPING = {
     EventType.MESSAGE           : {"color": 10526880,   "emoji": "information_source"},
+    EventType.SERVER_STARTING   : {"color": 10526880,   "emoji": "rocket"},
     EventType.START             : {"color": 3256064,    "emoji": "green_square"},
     EventType.REGISTERED        : {"color": 10526880,   "emoji": "white_check_mark"},
     EventType.SHUTDOWN          : {"color": 14440960,   "emoji": "orange_square"},
@@ -834,7 +1665,23 @@ DISCORD_EVENT_EXTRA_MAPPING = {
     EventType.PLAYER_LEAVE      : {"color": 6553800,    "emoji": "outbox_tray"},
     EventType.COMMAND           : {"color": 15118080,   "emoji": "wrench"},
     EventType.SAVE              : {"color": 7274240,    "emoji": "file_cabinet"},
-    EventType.SAVEGAME_CHANGE   : {"color": 15118080,   "emoji": "dividers"}
+    EventType.SAVEGAME_CHANGE   : {"color": 15118080,   "emoji": "dividers"},
+    EventType.PLAYER_BANNED     : {"color": 13764616,   "emoji": "no_entry"},
+    EventType.SHUTDOWN_WARNING  : {"color": 14440960,   "emoji": "hourglass"},
+    EventType.RESTARTED         : {"color": 3256064,    "emoji": "arrows_counterclockwise"},
+    EventType.CRASH_LOOP        : {"color": 13764616,   "emoji": "rotating_light"},
+    EventType.BACKUP_CREATED    : {"color": 7274240,    "emoji": "floppy_disk"},
+    EventType.UPDATE_INSTALLED  : {"color": 3447003,    "emoji": "arrow_up"},
+    EventType.AUTOSAVE          : {"color": 9807270,    "emoji": "floppy_disk"},
+    EventType.SERVER_ERROR      : {"color": 16711680,   "emoji": "x"},
+    EventType.SERVER_CONFIG_CHANGE : {"color": 15118080, "emoji": "gear"},
+    EventType.CONFIG_RELOADED   : {"color": 15118080,   "emoji": "arrows_counterclockwise"},
+    EventType.EXTERNAL_SAVE     : {"color": 7274240,    "emoji": "file_cabinet"},
+    EventType.MAINTENANCE_MODE  : {"color": 15118080,   "emoji": "construction"},
+    EventType.INSTALL_INCOMPLETE : {"color": 16753920,  "emoji": "warning"},
+    EventType.HEARTBEAT         : {"color": 9807270,    "emoji": "heartbeat"},
+    EventType.KICK_ALL          : {"color": 15105570,   "emoji": "boot"},
+    EventType.RESTART_ON_EVENT  : {"color": 16753920,   "emoji": "arrows_counterclockwise"}
 }
 
 class DiscordNotificationHandler(QueuedNotificationHandler):
@@ -842,9 +1689,18 @@ class DiscordNotificationHandler(QueuedNotificationHandler):
         Queued Notification handler that sends event messages to a discord webhook
     """
     
-    def __init__(self, webhook_url, name="Server", event_whitelist=set([e for e in EventType]), event_formats=DEFAULT_EVENT_FORMATS, extra_formats=DISCORD_EVENT_EXTRA_MAPPING):
+    def __init__(self, webhook_url, colors={}, thumbnail_url=None, author_icon_url=None, silent_level=logging.WARNING, name="Server", event_whitelist=set([e for e in EventType]), event_formats=DEFAULT_EVENT_FORMATS, extra_formats=DISCORD_EVENT_EXTRA_MAPPING, timeout=10):
         self.webhook_url = webhook_url
-        self.extra_mapping = extra_formats
+        self.thumbnail_url = thumbnail_url
+        self.author_icon_url = author_icon_url
+        self.silent_level = silent_level
+        self.timeout = timeout
+        
+        # Apply overridden colors without changing the default constants
+        self.extra_mapping = {et: dict(extra) for et, extra in extra_formats.items()}
+        
+        for et, color in colors.items():
+            self.extra_mapping[et]["color"] = color
         
         # This is to prevent overriding default constants
         event_formats = event_formats.copy()
@@ -859,7 +1715,12 @@ class DiscordNotificationHandler(QueuedNotificationHandler):
         
         self.logger.debug(f"Event Whitelist: {list(self.whitelist)}")
     
-    def _send_message(self, event_type, message):
+    def set_templates(self, templates):
+        """ Same as NotificationHandler.set_templates, but adds the format strings to the Discord Message template first """
+        
+        super().set_templates({et: safeformat(DISCORD_MESSAGE_TEMPLATE, message=escape_json_string(fmt)) for et, fmt in templates.items()})
+    
+    def _send_message(self, event_type, message, fields={}):
         extra = self.extra_mapping[event_type]
         
         timestamp = datetime.now(timezone.utc).strftime("%Y-%m-%dT%H:%M:%S.000Z")
@@ -869,10 +1730,41 @@ class DiscordNotificationHandler(QueuedNotificationHandler):
         # Normalize JSON
         message_json = json.loads(message_json)
         
+        # Images identifying the server, such that notifications of different servers can be told apart
+        if self.thumbnail_url:
+            message_json["embeds"][0]["thumbnail"] = {"url": self.thumbnail_url}
+        
+        if self.author_icon_url:
+            message_json["embeds"][0]["author"]["icon_url"] = self.author_icon_url
+        
+        # Only ping the channel members for events, that need attention
+        if DEFAULT_LEVEL_MAPPING.get(event_type, logging.INFO) < self.silent_level:
+            message_json["flags"] = DISCORD_SUPPRESS_NOTIFICATIONS_FLAG
+        
+        # Add event parameters as embed fields. This is done after parsing, so the values don't need to be escaped
+        for key, value in fields.items():
+            # Discord rejects embeds with empty field values or values longer than 1024 characters
+            if value == "":
+                continue
+            
+            message_json["embeds"][0]["fields"].append({
+                "name": key.replace("_", " ").title(),
+                "value": value[:1024],
+                "inline": True
+            })
+        
         try:
-            resp = net.post_request(self.webhook_url, headers=DISCORD_HEADERS, jsonData=message_json)
+            resp = net.post_request(self.webhook_url, headers=DISCORD_HEADERS, jsonData=message_json, timeout=self.timeout)
         except Exception as e:
             self.logger.error(f"Error while sending Discord notification: {str(e)}")
+            return False
+        
+        # Errors are returned as a response, so the message is only delivered, if the server accepted it
+        if not (200 <= resp.getcode() < 300):
+            self.logger.error(f"Error while sending Discord notification: Server answered with status {resp.getcode()}")
+            return False
+        
+        return True
 
 NTFY_MESSAGE_TEMPLATE = """{{
     "topic": "{topic}",
@@ -894,6 +1786,7 @@ NTFY_HEADERS = {
 
 NTFY_EVENT_EXTRA_MAPPING = {
     EventType.MESSAGE           : {"priority": 2, "emoji": "information_source"},
+    EventType.SERVER_STARTING   : {"priority": 2, "emoji": "rocket"},
     EventType.START             : {"priority": 4, "emoji": "green_square"},
     EventType.REGISTERED        : {"priority": 3, "emoji": "white_check_mark"},
     EventType.SHUTDOWN          : {"priority": 4, "emoji": "orange_square"},
@@ -902,7 +1795,23 @@ NTFY_EVENT_EXTRA_MAPPING = {
     EventType.PLAYER_LEAVE      : {"priority": 3, "emoji": "outbox_tray"},
     EventType.COMMAND           : {"priority": 3, "emoji": "wrench"},
     EventType.SAVE              : {"priority": 2, "emoji": "file_cabinet"},
-    EventType.SAVEGAME_CHANGE   : {"priority": 2, "emoji": "card_index_dividers"}
+    EventType.SAVEGAME_CHANGE   : {"priority": 2, "emoji": "card_index_dividers"},
+    EventType.PLAYER_BANNED     : {"priority": 3, "emoji": "no_entry"},
+    EventType.SHUTDOWN_WARNING  : {"priority": 3, "emoji": "hourglass"},
+    EventType.RESTARTED         : {"priority": 3, "emoji": "arrows_counterclockwise"},
+    EventType.CRASH_LOOP        : {"priority": 5, "emoji": "rotating_light"},
+    EventType.BACKUP_CREATED    : {"priority": 2, "emoji": "floppy_disk"},
+    EventType.UPDATE_INSTALLED  : {"priority": 3, "emoji": "arrow_up"},
+    EventType.AUTOSAVE          : {"priority": 1, "emoji": "floppy_disk"},
+    EventType.SERVER_ERROR      : {"priority": 4, "emoji": "x"},
+    EventType.SERVER_CONFIG_CHANGE : {"priority": 3, "emoji": "gear"},
+    EventType.CONFIG_RELOADED   : {"priority": 2, "emoji": "arrows_counterclockwise"},
+    EventType.EXTERNAL_SAVE     : {"priority": 2, "emoji": "file_cabinet"},
+    EventType.MAINTENANCE_MODE  : {"priority": 3, "emoji": "construction"},
+    EventType.INSTALL_INCOMPLETE : {"priority": 4, "emoji": "warning"},
+    EventType.HEARTBEAT         : {"priority": 1, "emoji": "heartbeat"},
+    EventType.KICK_ALL          : {"priority": 3, "emoji": "boot"},
+    EventType.RESTART_ON_EVENT  : {"priority": 4, "emoji": "arrows_counterclockwise"}
 }
 
 class NTFYNotificationHandler(QueuedNotificationHandler):
@@ -910,10 +1819,20 @@ class NTFYNotificationHandler(QueuedNotificationHandler):
         Queued Notificationm handler that sends event messages to an ntfy instance
     """
     
-    def __init__(self, topic, ntfy_url="https://ntfy.sh", name="Server", event_whitelist=set([e for e in EventType]), event_formats=DEFAULT_EVENT_FORMATS, extra_formats=NTFY_EVENT_EXTRA_MAPPING):
+    def __init__(self, topic, ntfy_url="https://ntfy.sh", click={}, actions={}, priorities={}, topics={}, level_topics={}, name="Server", event_whitelist=set([e for e in EventType]), event_formats=DEFAULT_EVENT_FORMATS, extra_formats=NTFY_EVENT_EXTRA_MAPPING, timeout=10):
         self.topic = topic
+        self.topics = topics
+        self.level_topics = level_topics
+        self.click = click
+        self.actions = actions
+        self.timeout = timeout
         self.ntfy_url = ntfy_url
-        self.extra_mapping = extra_formats
+        
+        # Apply overridden priorities without changing the default constants
+        self.extra_mapping = {et: dict(extra) for et, extra in extra_formats.items()}
+        
+        for et, priority in priorities.items():
+            self.extra_mapping[et]["priority"] = priority
         
         # This is to prevent overriding default constants
         event_formats = event_formats.copy()
@@ -928,18 +1847,337 @@ class NTFYNotificationHandler(QueuedNotificationHandler):
         
         self.logger.debug(f"Event Whitelist: {list(self.whitelist)}")
     
-    def _send_message(self, event_type, message):
+    def set_templates(self, templates):
+        """ Same as NotificationHandler.set_templates, but adds the format strings to the NTFY Message template first """
+        
+        super().set_templates({et: safeformat(NTFY_MESSAGE_TEMPLATE, message=escape_json_string(fmt), topic=self.topic) for et, fmt in templates.items()})
+    
+    def get_topic(self, event_type):
+        """
+            Returns the topic to send events of type {event_type} to.
+            A topic set for the event type is preferred over the topic of the highest level the event reaches, which is preferred over the base topic
+        """
+        
+        if event_type in self.topics:
+            return self.topics[event_type]
+        
+        event_level = DEFAULT_LEVEL_MAPPING.get(event_type, logging.INFO)
+        reached_levels = [level for level in self.level_topics if level <= event_level]
+        
+        if len(reached_levels) > 0:
+            return self.level_topics[max(reached_levels)]
+        
+        return self.topic
+    
+    def _send_message(self, event_type, message, fields={}):
         extra = self.extra_mapping[event_type]
         
         message_json = safeformat(message, keep_escape=False, event_type=event_type.value, priority=extra["priority"], emoji=extra["emoji"])
         
         # Normalize JSON
         message_json = json.loads(message_json)
+        message_json["topic"] = self.get_topic(event_type)
+        
+        # Add click URL and action buttons configured for the event
+        if event_type in self.click:
+            message_json["click"] = self.click[event_type]
+        
+        if event_type in self.actions:
+            message_json["actions"] = self.actions[event_type]
         
         try:
-            resp = net.post_request(self.ntfy_url, headers=NTFY_HEADERS, jsonData=message_json)
+            resp = net.post_request(self.ntfy_url, headers=NTFY_HEADERS, jsonData=message_json, timeout=self.timeout)
         except Exception as e:
             self.logger.error(f"Error while sending ntfy notification: {str(e)}")
+            return False
+        
+        # Errors are returned as a response, so the message is only delivered, if the server accepted it
+        if not (200 <= resp.getcode() < 300):
+            self.logger.error(f"Error while sending ntfy notification: Server answered with status {resp.getcode()}")
+            return False
+        
+        return True
+
+GOTIFY_MESSAGE_TEMPLATE = """{{
+    "title": "{message}",
+    "message": "{name}",
+    "priority": {priority}
+}}"""
+
+GOTIFY_HEADERS = {
+    'content-type': 'application/json; charset=utf-8',
+    'User-Agent': f"AstroTuxLauncher/{LAUNCHER_VERSION}",
+    'Accept': 'application/json'
+}
+
+# Gotify priorities range from 0 to 10, while the ntfy priorities of the events range from 1 to 5
+GOTIFY_PRIORITY_FACTOR = 2
+
+class GotifyNotificationHandler(QueuedNotificationHandler):
+    """
+        Queued Notification handler that sends event messages to a Gotify server
+    """
+    
+    def __init__(self, token, gotify_url, name="Server", event_whitelist=set([e for e in EventType]), event_formats=DEFAULT_EVENT_FORMATS, extra_formats=NTFY_EVENT_EXTRA_MAPPING, timeout=10):
+        self.token = token
+        self.gotify_url = gotify_url.rstrip("/")
+        self.extra_mapping = extra_formats
+        self.timeout = timeout
+        
+        # This is to prevent overriding default constants
+        event_formats = event_formats.copy()
+        
+        # Add message formats to Gotify Message template
+        for et in EventType:
+            event_formats[et] = safeformat(GOTIFY_MESSAGE_TEMPLATE, message=event_formats[et])
+        
+        super().__init__(name, event_whitelist, event_formats)
+        
+        self.logger = logging.getLogger("GotifyNotify")
+        
+        self.logger.debug(f"Event Whitelist: {list(self.whitelist)}")
+    
+    def set_templates(self, templates):
+        """ Same as NotificationHandler.set_templates, but adds the format strings to the Gotify Message template first """
+        
+        super().set_templates({et: safeformat(GOTIFY_MESSAGE_TEMPLATE, message=escape_json_string(fmt)) for et, fmt in templates.items()})
+    
+    def _send_message(self, event_type, message, fields={}):
+        extra = self.extra_mapping[event_type]
+        
+        message_json = safeformat(message, keep_escape=False, priority=extra["priority"] * GOTIFY_PRIORITY_FACTOR)
+        
+        # Normalize JSON
+        message_json = json.loads(message_json)
+        
+        # Add event parameters to the message body
+        for key, value in fields.items():
+            message_json["message"] += f"\n{key.replace('_', ' ').title()}: {value}"
+        
+        try:
+            resp = net.post_request(f"{self.gotify_url}/message?token={urlparse.quote(self.token)}", headers=GOTIFY_HEADERS, jsonData=message_json, timeout=self.timeout)
+        except Exception as e:
+            self.logger.error(f"Error while sending Gotify notification: {str(e)}")
+            return False
+        
+        # Errors are returned as a response, so the message is only delivered, if the server accepted it
+        if not (200 <= resp.getcode() < 300):
+            self.logger.error(f"Error while sending Gotify notification: Server answered with status {resp.getcode()}")
+            return False
+        
+        return True
+
+# Unicode characters of the emojis used for events, for services that don't support emoji shortcodes
+EMOJI_CHARACTERS = {
+    "arrow_up"                  : "\u2b06\ufe0f",
+    "arrows_counterclockwise"   : "\U0001f504",
+    "boot"                      : "\U0001f462",
+    "card_index_dividers"       : "\U0001f5c2\ufe0f",
+    "construction"              : "\U0001f6a7",
+    "dividers"                  : "\U0001f5c2\ufe0f",
+    "file_cabinet"              : "\U0001f5c4\ufe0f",
+    "floppy_disk"               : "\U0001f4be",
+    "gear"                      : "\u2699\ufe0f",
+    "green_square"              : "\U0001f7e9",
+    "heartbeat"                 : "\U0001f493",
+    "hourglass"                 : "\u231b",
+    "inbox_tray"                : "\U0001f4e5",
+    "information_source"        : "\u2139\ufe0f",
+    "no_entry"                  : "\u26d4",
+    "orange_square"             : "\U0001f7e7",
+    "outbox_tray"               : "\U0001f4e4",
+    "red_square"                : "\U0001f7e5",
+    "rocket"                    : "\U0001f680",
+    "rotating_light"            : "\U0001f6a8",
+    "warning"                   : "\u26a0\ufe0f",
+    "white_check_mark"          : "\u2705",
+    "wrench"                    : "\U0001f527",
+    "x"                         : "\u274c"
+}
+
+MATRIX_HEADERS = {
+    'content-type': 'application/json; charset=utf-8',
+    'User-Agent': f"AstroTuxLauncher/{LAUNCHER_VERSION}",
+    'Accept': 'application/json'
+}
+
+class MatrixNotificationHandler(QueuedNotificationHandler):
+    """
+        Queued Notification handler that sends event messages as notices to a Matrix room
+    """
+    
+    def __init__(self, homeserver_url, access_token, room_id, name="Server", event_whitelist=set([e for e in EventType]), event_formats=DEFAULT_EVENT_FORMATS, extra_formats=DISCORD_EVENT_EXTRA_MAPPING, timeout=10):
+        self.homeserver_url = homeserver_url.rstrip("/")
+        self.access_token = access_token
+        self.room_id = room_id
+        self.extra_mapping = extra_formats
+        self.timeout = timeout
+        
+        super().__init__(name, event_whitelist, event_formats)
+        
+        self.logger = logging.getLogger("MatrixNotify")
+        
+        self.logger.debug(f"Event Whitelist: {list(self.whitelist)}")
+    
+    def _send_message(self, event_type, message, fields={}):
+        emoji_name = self.extra_mapping[event_type]["emoji"]
+        
+        if emoji_name not in EMOJI_CHARACTERS:
+            self.logger.warning(f"No character known for emoji '{emoji_name}', sending '{event_type.value}' notification without it")
+        
+        emoji = EMOJI_CHARACTERS.get(emoji_name, "")
+        
+        body = f"{emoji} {message} ({self.name})"
+        formatted_body = f"{emoji} <b>{html.escape(message)}</b><br>{html.escape(self.name)}"
+        
+        # Add event parameters to the message
+        for key, value in fields.items():
+            body += f"\n{key.replace('_', ' ').title()}: {value}"
+            formatted_body += f"<br><i>{html.escape(key.replace('_', ' ').title())}:</i> {html.escape(value)}"
+        
+        message_json = {
+            "msgtype": "m.notice",
+            "body": body,
+            "format": "org.matrix.custom.html",
+            "formatted_body": formatted_body
+        }
+        
+        headers = MATRIX_HEADERS.copy()
+        headers["Authorization"] = f"Bearer {self.access_token}"
+        
+        # Every event needs a unique transaction ID, which is kept when retrying it, such that the homeserver doesn't send it twice
+        txn_id = self.thread.current_event_id
+        
+        try:
+            resp = net.put_request(f"{self.homeserver_url}/_matrix/client/v3/rooms/{urlparse.quote(self.room_id, safe='')}/send/m.room.message/{txn_id}", headers=headers, jsonData=message_json, timeout=self.timeout)
+        except Exception as e:
+            self.logger.error(f"Error while sending Matrix notification: {str(e)}")
+            return False
+        
+        # Errors are returned as a response, so the message is only delivered, if the server accepted it
+        if not (200 <= resp.getcode() < 300):
+            self.logger.error(f"Error while sending Matrix notification: Server answered with status {resp.getcode()}")
+            return False
+        
+        return True
+
+class AppriseNotificationHandler(QueuedNotificationHandler):
+    """
+        Queued Notification handler that sends event messages to multiple services given as Apprise URLs using the apprise executable.
+        The URLs are passed in a temporary config file, as they contain secrets, that other users could read from the command line
+    """
+    
+    def __init__(self, urls, apprise_exec="apprise", name="Server", event_whitelist=set([e for e in EventType]), event_formats=DEFAULT_EVENT_FORMATS, timeout=10):
+        self.urls = urls
+        self.apprise_exec = apprise_exec
+        self.timeout = timeout
+        self.sent_urls = {}     # URLs, that an event was already sent to, by event ID, such that retries only go to the failed ones
+        
+        super().__init__(name, event_whitelist, event_formats)
+        
+        self.logger = logging.getLogger("AppriseNotify")
+        
+        self.logger.debug(f"Event Whitelist: {list(self.whitelist)}")
+    
+    def _send_message(self, event_type, message, fields={}):
+        body = self.name
+        
+        # Add event parameters to the message body
+        for key, value in fields.items():
+            body += f"\n{key.replace('_', ' ').title()}: {value}"
+        
+        event_id = self.thread.current_event_id
+        sent_urls = self.sent_urls.setdefault(event_id, set())
+        
+        success = True
+        
+        # Send to every URL separately, such that failures can be logged for each service
+        for url in self.urls:
+            if url in sent_urls:
+                continue
+            
+            try:
+                self._run_apprise(message, body, url)
+                sent_urls.add(url)
+            except Exception as e:
+                self.logger.error(f"Error while sending Apprise notification to '{redact_url(url, keep_host=False)}': {str(e)}")
+                success = False
+        
+        if success:
+            self.sent_urls.pop(event_id, None)
+        
+        return success
+    
+    def _run_apprise(self, title, body, url):
+        """ Sends a notification with {title} and {body} to the Apprise URL {url}, raising an exception, if it fails """
+        
+        # The temporary file is only readable by the current user
+        with tempfile.NamedTemporaryFile("w", prefix="astrotux_apprise_", suffix=".txt") as config_file:
+            config_file.write(url + "\n")
+            config_file.flush()
+            
+            proc = subprocess.run([self.apprise_exec, "-t", title, "-b", body, "--config", config_file.name], capture_output=True, text=True, timeout=self.timeout)
+        
+        if proc.returncode != 0:
+            raise RuntimeError(proc.stderr.strip())
+
+class EmailNotificationHandler(QueuedNotificationHandler):
+    """
+        Queued Notification handler that sends event messages as emails using an SMTP server
+        
+        Arguments:
+            - security: "starttls" to upgrade the connection using STARTTLS, "tls" to use implicit TLS or "none" for an unencrypted connection
+    """
+    
+    def __init__(self, smtp_host, smtp_port, sender, recipients, username=None, password=None, security="starttls", name="Server", event_whitelist=set([e for e in EventType]), event_formats=DEFAULT_EVENT_FORMATS, timeout=10):
+        self.smtp_host = smtp_host
+        self.smtp_port = smtp_port
+        self.sender = sender
+        self.recipients = recipients
+        self.username = username
+        self.password = password
+        self.security = security
+        self.timeout = timeout
+        
+        super().__init__(name, event_whitelist, event_formats)
+        
+        self.logger = logging.getLogger("EmailNotify")
+        
+        self.logger.debug(f"Event Whitelist: {list(self.whitelist)}")
+    
+    def _send_message(self, event_type, message, fields={}):
+        mail = EmailMessage()
+        mail["Subject"] = f"[{self.name}] {message}"
+        mail["From"] = self.sender
+        mail["To"] = ", ".join(self.recipients)
+        
+        body = f"{message}\n\nServer: {self.name}\nEvent: {event_type.value}"
+        
+        # Add event parameters to the mail body
+        for key, value in fields.items():
+            body += f"\n{key.replace('_', ' ').title()}: {value}"
+        
+        body += f"\n\n-- \nAstroTuxLauncher v{LAUNCHER_VERSION}"
+        
+        mail.set_content(body)
+        
+        try:
+            if self.security == "tls":
+                smtp = smtplib.SMTP_SSL(self.smtp_host, self.smtp_port, timeout=self.timeout, context=ssl.create_default_context())
+            else:
+                smtp = smtplib.SMTP(self.smtp_host, self.smtp_port, timeout=self.timeout)
+            
+            with smtp:
+                if self.security == "starttls":
+                    smtp.starttls(context=ssl.create_default_context())
+                
+                if self.username:
+                    smtp.login(self.username, self.password)
+                
+                smtp.send_message(mail)
+        except Exception as e:
+            self.logger.error(f"Error while sending email notification: {str(e)}")
+            return False
 
 #
 #   Miscellaneous
@@ -947,8 +2185,11 @@ class NTFYNotificationHandler(QueuedNotificationHandler):
 
 PROC_FORMAT="[{name}] {message}"
 
-def run_proc_with_logging(args, name, format=PROC_FORMAT, sleep_time=0.05, level=logging.INFO, alive_bar=None, **popen_args):
-    """ Runs a process and outputs its output using the logging module and waits for it to finish """
+def run_proc_with_logging(args, name, format=PROC_FORMAT, sleep_time=0.05, level=logging.INFO, alive_bar=None, line_callback=None, **popen_args):
+    """
+        Runs a process and outputs its output using the logging module and waits for it to finish.
+        If {line_callback} is given, it is called with every line of output, e.g. for parsing progress information
+    """
     
     # Create process with piped stdout/stderr
     process = subprocess.Popen(args, stderr=subprocess.PIPE, stdout=subprocess.PIPE, bufsize=1, close_fds=True, text=True, **popen_args)
@@ -977,6 +2218,9 @@ def run_proc_with_logging(args, name, format=PROC_FORMAT, sleep_time=0.05, level
         else:
             line = line.replace("\n", "")   # Remove newline character, since it it unnecessary
             LOGGER.log(level, safeformat(format, name=name, message=line))
+            
+            if line_callback:
+                line_callback(line)
         
         if alive_bar:
             alive_bar()
@@ -986,6 +2230,36 @@ def run_proc_with_logging(args, name, format=PROC_FORMAT, sleep_time=0.05, level
     return process.poll()
 
 
+# Class for calling a heartbeat callback in a fixed interval
+class HeartbeatThread(threading.Thread):
+    """
+        Thread, that calls a callback in a fixed interval until it is stopped
+        
+        Arguments:
+            - interval: The time in seconds between calls of the callback
+            - callback: The function to call, which decides itself, wether a heartbeat is sent
+    """
+    
+    def __init__(self, interval, callback, name="heartbeat-thread"):
+        self.interval = interval
+        self.callback = callback
+        
+        self._stop_event = threading.Event()
+        self.logger = logging.getLogger(name)
+        
+        super(HeartbeatThread, self).__init__(name=name)
+        self.daemon = True
+    
+    def stop(self):
+        self._stop_event.set()
+    
+    def run(self):
+        while not self._stop_event.wait(self.interval):
+            try:
+                self.callback()
+            except Exception as e:
+                self.logger.error(f"Error while sending heartbeat: {str(e)}")
+
 # Class for sending regular status updates to an http endpoint
 class StatusUpdaterThread(threading.Thread):
     def __init__(self, base_url, status=True, message=None, timeout=120, status_mapping={True: "up", False: "down"}, parameter_name_map={"message": "msg", "status": "status"}, name="status-updater-thread"):
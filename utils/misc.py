@@ -1,20 +1,43 @@
 from os import path
 import os
+from urllib.parse import urlparse
+import re
+import unicodedata
+import logging
 
 LAUNCHER_VERSION="1.1.6"
 
+LOGGER = logging.getLogger("Launcher")
+
+# Expected format of the version in the 'build.version' file of the Astroneer Server installation
+BUILD_VERSION_REGEX = re.compile(r"^\d+(\.\d+)+$")
+
 CONTROL_CODES_SUPPORTED = None
 
 # If TERM environment variable contains "coloronly", disable stuff that uses ANSI escape codes other than color
 if ("TERM" in os.environ) and ("coloronly" in os.environ["TERM"]):
     CONTROL_CODES_SUPPORTED = False
 
+# Config keys, whose values are masked when they are logged
+SECRET_URL_KEYS = ["webhookURL", "serverURL", "EndpointURL", "PingURL", "urls"]
+SECRET_VALUE_KEYS = ["topic", "token", "accessToken", "password", "ConsolePassword", "ServerPassword"]
+# Config keys of dictionaries, whose values are all masked when they are logged
+SECRET_MAPPING_KEYS = ["topics"]
+REDACTED = "***"
+
+# Reference to an environment variable (${NAME}) or a file (${file:/path/to/file}) in a config value
+CONFIG_REFERENCE_REGEX = re.compile(r"\$\{(file:)?([^}]+)\}")
+
 def ExcludeIfNone(value):
     """Do not include field for None values"""
     return value is None
 
 def read_build_version(astro_path):
-    """ Read build version of Astroneer Server installation using the 'build.version file' """
+    """
+        Read build version of Astroneer Server installation using the 'build.version file'
+        
+        Returns: The build version or None, if no installation is present
+    """
     
     verfile_path = path.join(astro_path, "build.version")
     
@@ -23,6 +46,127 @@ def read_build_version(astro_path):
         return None
     
     with open(verfile_path, "r") as vf:
-        verstring = vf.readline()[:-10]
+        verstring = vf.readline()[:-10].strip()
+    
+    # The file is empty for a short time while the server is being installed, which is not a problem
+    if verstring == "":
+        LOGGER.debug("The build.version file of the Astroneer Dedicated Server is empty, assuming no installation is present")
+        return None
+    
+    if not BUILD_VERSION_REGEX.match(verstring):
+        LOGGER.error(f"Could not parse build version of the Astroneer Dedicated Server from build.version file: '{verstring}'")
+    
+    return verstring
+
+def resolve_references(data):
+    """
+        Creates a copy of the (config) dictionary {data}, in which references in string values are replaced.
+        '${NAME}' is replaced with the value of the environment variable NAME and '${file:/path}' with the contents of the file,
+        such that secrets don't have to be stored in the config file itself
+        
+        Arguments:
+            - data: The dictionary to resolve. Nested dictionaries and lists are resolved too
+        
+        Returns: The resolved copy of {data}
+    """
+    
+    if isinstance(data, list):
+        return [resolve_references(item) for item in data]
+    
+    if isinstance(data, dict):
+        return {key: resolve_references(value) for key, value in data.items()}
+    
+    if not isinstance(data, str):
+        return data
+    
+    def replace(match):
+        if match.group(1):
+            try:
+                with open(match.group(2), "r") as rf:
+                    return rf.read().strip()
+            except OSError as e:
+                raise ValueError(f"Could not read file '{match.group(2)}' referenced in config: {str(e)}")
+        
+        if not (match.group(2) in os.environ):
+            raise ValueError(f"Environment variable '{match.group(2)}' referenced in config is not set")
+        
+        return os.environ[match.group(2)]
+    
+    return CONFIG_REFERENCE_REGEX.sub(replace, data)
+
+def normalize_name(name):
+    """
+        Normalizes the (player) name {name} for comparing it case-insensitively,
+        such that names with the same characters in different unicode representations or cases match
+    """
+    
+    return unicodedata.normalize("NFC", name).casefold()
+
+def format_duration(seconds):
+    """ Formats the duration {seconds} in a human readable way, e.g. '1d 2h 3m 4s' """
+    
+    seconds = int(seconds)
+    days, seconds = divmod(seconds, 86400)
+    hours, seconds = divmod(seconds, 3600)
+    minutes, seconds = divmod(seconds, 60)
+    
+    parts = [f"{value}{unit}" for value, unit in [(days, "d"), (hours, "h"), (minutes, "m")] if value > 0]
+    parts.append(f"{seconds}s")
+    
+    return " ".join(parts)
+
+def redact_url(url, keep_host=True):
+    """
+        Masks everything after the host of {url}, as e.g. webhook URLs contain secret tokens in their path.
+        If {keep_host} is False, the host is masked too, as e.g. Apprise URLs can contain secret tokens in place of the host
+    """
+    
+    if not url:
+        return url
+    
+    parsed = urlparse(url)
+    
+    if not keep_host:
+        return f"{parsed.scheme}://{REDACTED}"
+    
+    if parsed.hostname is None:
+        return REDACTED
+    
+    host = parsed.hostname if parsed.port is None else f"{parsed.hostname}:{parsed.port}"
+    
+    if (parsed.path.strip("/") == "") and (parsed.query == ""):
+        return f"{parsed.scheme}://{host}"
+    
+    return f"{parsed.scheme}://{host}/{REDACTED}"
+
+def redact_secrets(data):
+    """
+        Creates a copy of the (config) dictionary {data} with the values of secret keys masked, such that it can be logged safely
+        
+        Arguments:
+            - data: The dictionary to redact. Nested dictionaries and lists are redacted too
+        
+        Returns: The redacted copy of {data}
+    """
+    
+    if isinstance(data, list):
+        return [redact_secrets(item) for item in data]
+    
+    if not isinstance(data, dict):
+        return data
+    
+    redacted = {}
+    
+    for key, value in data.items():
+        if (key in SECRET_URL_KEYS) and isinstance(value, str):
+            redacted[key] = redact_url(value)
+        elif (key in SECRET_URL_KEYS) and isinstance(value, list):
+            redacted[key] = [redact_url(url, keep_host=False) if isinstance(url, str) else url for url in value]
+        elif (key in SECRET_VALUE_KEYS) and value:
+            redacted[key] = REDACTED
+        elif (key in SECRET_MAPPING_KEYS) and isinstance(value, dict):
+            redacted[key] = {k: REDACTED if v else v for k, v in value.items()}
+        else:
+            redacted[key] = redact_secrets(value)
     
-    return verstring.strip()
\ No newline at end of file
+    return redacted
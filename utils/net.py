@@ -54,7 +54,34 @@ def post_request(url, headers={}, jsonData={}, timeout=5):
         Returns: The data response from the request or an HTTPError
     """
     
-    req = request.Request(url)
+    return json_request(url, "POST", headers, jsonData, timeout)
+
+def put_request(url, headers={}, jsonData={}, timeout=5):
+    """
+        Perform a PUT request to {url} using the specified {headers} containing the specified {jsonData}.
+        
+        Arguments: see post_request
+        
+        Returns: The data response from the request or an HTTPError
+    """
+    
+    return json_request(url, "PUT", headers, jsonData, timeout)
+
+def json_request(url, method, headers={}, jsonData={}, timeout=5):
+    """
+        Perform a request with the given HTTP {method} to {url} using the specified {headers} containing the specified {jsonData}.
+        
+        Arguments:
+            - url: The URL to perform the request on
+            - method: The HTTP method to use
+            - [headers]: see post_request
+            - [jsonData]: see post_request
+            - [timeout]: see post_request
+        
+        Returns: The data response from the request or an HTTPError
+    """
+    
+    req = request.Request(url, method=method)
     
     # Stringify JSON data
     if jsonData != {}:
@@ -224,6 +251,26 @@ def nonlocal_socket_server(port):
     finally:
         server_socket.close()
 
+def net_test_server(ip, port):
+    """
+        Test, if the running Dedicated Server at {ip}:{port} is reachable via UDP from outside of the local network by using external service.
+        In contrast to net_test_nonlocal, the server itself has to answer, so the port has to be bound by it already
+        
+        Returns: Wether the server is reachable or None, if the external service could not be used to check it
+    """
+    
+    try:
+        resp = post_request(f"https://servercheck.spycibot.com/api?ip_port={ip}:{port}", timeout=10)
+        
+        # post_request returns error responses instead of raising them
+        if not (200 <= resp.getcode() < 300):
+            raise ValueError(f"External service answered with status {resp.getcode()}")
+        
+        return bool(json.load(resp)["Server"])
+    except Exception as e:
+        LOGGER.debug(f"Error while using external service: {str(e)}")
+        return None
+
 def net_test_nonlocal(ip, port):
     """ Test connection to host with {ip} on {port} via UDP from outside of the local network by using external service """
     # Setup receive thread to repsond to outside message
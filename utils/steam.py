@@ -11,6 +11,9 @@ import shutil
 import subprocess
 import time
 import logging
+import re
+import sys
+from urllib.error import URLError
 from utils.interface import run_proc_with_logging, safeformat, AP_SPINNER, AP_BAR
 from alive_progress import alive_bar
 from utils.misc import CONTROL_CODES_SUPPORTED
@@ -19,6 +22,24 @@ DEPOTDL_LATEST_ZIP_URL="https://github.com/SteamRE/DepotDownloader/releases/late
 
 LOGGER = logging.getLogger("Steam")
 
+# Progress line of DepotDownloader, e.g. ' 12.34% Astro/Content/Paks/Astro-WindowsServer.pak'
+DEPOTDL_PROGRESS_REGEX = re.compile(r"^\s*(\d{1,3}(?:\.\d+)?)%\s")
+
+# Summary line of DepotDownloader after the download has finished
+DEPOTDL_TOTAL_REGEX = re.compile(r"Total downloaded: (\d+) bytes")
+
+# Interval of the progress in percent, in which it is logged, if no progress bar can be shown
+DEPOTDL_LOG_PERCENT_STEP = 10
+
+# How often to try downloading DepotDownloader and the time in seconds to wait between tries
+DEPOTDL_DOWNLOAD_TRIES = 3
+DEPOTDL_DOWNLOAD_RETRY_DELAY = 5
+
+def is_executable(exec_path):
+    """ Returns wether {exec_path} points to a file, that can be executed by the current user """
+    
+    return path.isfile(exec_path) and os.access(exec_path, os.X_OK)
+
 def reporthook(blocks_done, block_size, file_size):
     size_trans = blocks_done * block_size
     trans_percentage = (size_trans / file_size) * 100
@@ -82,17 +103,27 @@ def dl_depotdownloader(dest_dir, execname="depotdownloader"):
         
         start_time = time.time()
         
-        with alive_bar(title="Downloading DepotDownloader", spinner=AP_SPINNER, bar=AP_BAR, manual=True, receipt=True, enrich_print=False, force_tty=CONTROL_CODES_SUPPORTED) as bar:
-            zip_path, _ = dl.download(bar)
-        
-        # Extract zip file into tmp dir
-        with zipfile.ZipFile(zip_path, "r") as zf:
-            zf.extractall(tmpdir)
+        for attempt in range(1, DEPOTDL_DOWNLOAD_TRIES + 1):
+            try:
+                with alive_bar(title="Downloading DepotDownloader", spinner=AP_SPINNER, bar=AP_BAR, manual=True, receipt=True, enrich_print=False, force_tty=CONTROL_CODES_SUPPORTED) as bar:
+                    zip_path, _ = dl.download(bar)
+                
+                # Extract zip file into tmp dir
+                with zipfile.ZipFile(zip_path, "r") as zf:
+                    zf.extractall(tmpdir)
+                
+                break
+            except (URLError, OSError, zipfile.BadZipFile) as e:
+                if attempt == DEPOTDL_DOWNLOAD_TRIES:
+                    raise
+                
+                LOGGER.warning(f"Downloading DepotDownloader failed ({type(e).__name__}: {str(e)}), trying again in {DEPOTDL_DOWNLOAD_RETRY_DELAY} seconds ({attempt}/{DEPOTDL_DOWNLOAD_TRIES})")
+                time.sleep(DEPOTDL_DOWNLOAD_RETRY_DELAY)
         
         dlexec_path = path.join(tmpdir, "DepotDownloader")
         
         if not path.isfile(dlexec_path):
-            raise FileNotFoundError("Executable not present after extraction")
+            raise FileNotFoundError("Executable not present in the downloaded release after extraction")
         
         dest_path = path.join(dest_dir, execname)
         
@@ -101,6 +132,9 @@ def dl_depotdownloader(dest_dir, execname="depotdownloader"):
         # Make file executable
         os.chmod(dest_path, 0o775)
         
+        if not is_executable(dest_path):
+            raise PermissionError(f"'{dest_path}' is not executable after extraction")
+        
         end_time = time.time()
         elapsed = end_time - start_time
         
@@ -130,9 +164,37 @@ def update_app(exec_path, app, os, directory):
     
     start_time = time.time()
     
+    # Without a terminal the progress bar isn't animated, so the progress is logged instead
+    interactive = (CONTROL_CODES_SUPPORTED is not False) and sys.stdout.isatty()
+    logged_percentage = -DEPOTDL_LOG_PERCENT_STEP
+    
     # Run update command, log output and wait until it is finished
-    with alive_bar(title=f"Updating app {app}", spinner=AP_SPINNER, bar=None, receipt=True, enrich_print=False, monitor=False, stats=False, force_tty=CONTROL_CODES_SUPPORTED) as bar:
-        proc_res = run_proc_with_logging(cmd_args, "DepotDL", level=logging.DEBUG, alive_bar=bar)
+    with alive_bar(title=f"Updating app {app}", spinner=AP_SPINNER, bar=AP_BAR, manual=True, receipt=True, enrich_print=False, monitor=True, stats=False, force_tty=CONTROL_CODES_SUPPORTED) as bar:
+        def handle_line(line):
+            nonlocal logged_percentage
+            
+            progress_match = DEPOTDL_PROGRESS_REGEX.match(line)
+            
+            if progress_match:
+                percentage = min(float(progress_match.group(1)), 100)
+                bar(percentage / 100)
+                
+                if not interactive and (percentage - logged_percentage >= DEPOTDL_LOG_PERCENT_STEP):
+                    logged_percentage = (percentage // DEPOTDL_LOG_PERCENT_STEP) * DEPOTDL_LOG_PERCENT_STEP
+                    LOGGER.info(f"[Update] {round(percentage)}%")
+                
+                return
+            
+            total_match = DEPOTDL_TOTAL_REGEX.search(line)
+            
+            if total_match:
+                LOGGER.info(f"Downloaded {int(total_match.group(1)) / (1024 ** 2):.1f} MiB")
+        
+        proc_res = run_proc_with_logging(cmd_args, "DepotDL", level=logging.DEBUG, line_callback=handle_line)
+        
+        # Nothing is downloaded, if all files are up to date already
+        if proc_res == 0:
+            bar(1)
     
     end_time = time.time()
     elapsed = end_time - start_time